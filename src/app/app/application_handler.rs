@@ -1,6 +1,7 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
 use crate::app::{App, UserEvent};
+use crate::graphics::WindowSettings;
 use crate::log::log;
 
 use std::hint::cold_path;
@@ -12,7 +13,16 @@ use winit::window::WindowId;
 
 impl ApplicationHandler<UserEvent> for App {
 	fn resumed(&mut self, event_loop: &ActiveEventLoop) {
-		self.graphics_context.init_with(event_loop, |context| {
+		// Prefer the window settings from the command
+		// line over the configured ones.
+
+		let settings = WindowSettings {
+			size: self.args.window_size.unwrap_or(self.config.window.size),
+
+			is_fullscreen: self.args.is_fullscreen || self.config.window.is_fullscreen,
+		};
+
+		self.graphics_context.init_with(event_loop, settings, |context| {
 			context.draw_map(&self.map, self.view_pan, self.view_scale);
 		});
 	}
@@ -50,6 +60,18 @@ impl ApplicationHandler<UserEvent> for App {
 			WindowEvent::Resized(size) => {
 				let graphics_context = self.graphics_context.unwrap_mut();
 				graphics_context.resize((size.width, size.height));
+
+				// Remember the window size for the next run,
+				// unless it is only temporary.
+
+				let size = (size.width, size.height);
+
+				let is_temporary = self.args.window_size.is_some() || self.args.is_fullscreen || self.config.window.is_fullscreen;
+
+				if !is_temporary && size != self.config.window.size {
+					self.config.window.size = size;
+					self.is_config_changed  = true;
+				}
 			}
 
 			// Ignore by default.
@@ -68,8 +90,18 @@ impl ApplicationHandler<UserEvent> for App {
 
 			self.tick();
 
-			let graphics_context = self.graphics_context.unwrap_mut();
-			graphics_context.draw_map(&self.map, self.view_pan, self.view_scale);
+			// Only redraw the columns that have changed.
+
+			if let Some(columns) = self.map.take_dirty() {
+				let graphics_context = self.graphics_context.unwrap_mut();
+				graphics_context.draw_map_columns(&self.map, self.view_pan, self.view_scale, columns);
+			}
+		}
+
+		if Instant::now() >= self.next_level_poll {
+			self.next_level_poll = Instant::now() + Self::LEVEL_POLL_INTERVAL;
+
+			self.poll_level_file();
 		}
 
 		let graphics_context = self.graphics_context.unwrap_mut();
@@ -92,6 +124,10 @@ impl ApplicationHandler<UserEvent> for App {
 	}
 
 	fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
+		if self.is_config_changed {
+			self.save_config();
+		}
+
 		log!("goodbye <3");
 	}
 
@@ -0,0 +1,47 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+use crate::error::{Error, Result};
+use crate::level::{BundledLevel, Level};
+use crate::log::log;
+
+use std::path::Path;
+
+impl App {
+	/// Checks that all bundled levels are valid and survive serialisation.
+	///
+	/// Each bundled level is written to TOML and read back, and the result must equal the original.
+	/// This includes the built-in levels, which are defined by their bundled copies.
+	pub(super) fn check_levels() -> Result<()> {
+		let mut failures = Vec::new();
+
+		for bundled in BundledLevel::ALL {
+			let name = bundled.name;
+
+			log!(debug, "checking bundled level \"{name}\"");
+
+			let path = Path::new(name).with_extension("toml");
+
+			let level = Level::from_toml(bundled.source, &path)?;
+
+			level.validate()?;
+
+			let loaded = Level::from_toml(&level.to_toml()?, &path)?;
+
+			if loaded != level {
+				log!(error, "bundled level \"{name}\" does not survive serialisation");
+
+				failures.push(name);
+				continue;
+			}
+
+			println!("level \"{name}\" is ok");
+		}
+
+		if let Some(&name) = failures.first() {
+			return Err(Error::LossyLevel(name.into()));
+		}
+
+		Ok(())
+	}
+}
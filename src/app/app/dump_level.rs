@@ -0,0 +1,22 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+use crate::error::Result;
+use crate::level::Level;
+
+impl App {
+	/// Prints a level as TOML.
+	///
+	/// Built-in levels are preferred over level files, so that the original level is always available as a template.
+	pub(super) fn dump_level(&self, name: &str) -> Result<()> {
+		let level = match Level::load_builtin(name) {
+			Some(level) => level,
+
+			None => self.load_level(name)?,
+		};
+
+		print!("{}", level.to_toml()?);
+
+		Ok(())
+	}
+}
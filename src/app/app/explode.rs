@@ -0,0 +1,51 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+use crate::log::log;
+use crate::map::Explosion;
+
+impl App {
+	/// Detonates an explosion in the map.
+	///
+	/// Players that are hit are dealt damage according to their distance to the explosion (see [`Explosion::damage_at`]).
+	pub(super) fn explode(&mut self, explosion: &Explosion) {
+		log!(debug, "detonating explosion at `{:?}` with radius `{}`", explosion.centre, explosion.radius);
+
+		// Players that have not been placed on the map
+		// cannot be hit.
+
+		let (indices, targets): (Vec<usize>, Vec<(f64, f64)>) = self
+			.players
+			.iter()
+			.enumerate()
+			.filter(|&(_, player)| player.is_spawned)
+			.map(|(index, player)| (index, player.position))
+			.unzip();
+
+		let hits = self.map.explode(explosion, targets.iter().copied(), &mut self.rng);
+
+		for hit in hits {
+			let (Some(&index), Some(&position)) = (indices.get(hit), targets.get(hit)) else {
+				continue;
+			};
+
+			let Some(player) = self.players.iter_mut().nth(index) else {
+				continue;
+			};
+
+			if !player.is_alive() {
+				continue;
+			}
+
+			let damage = explosion.damage_at(position);
+
+			player.health = player.health.saturating_sub(damage);
+
+			log!("player \"{}\" was hit for `{damage}` damage", player.name);
+
+			if !player.is_alive() {
+				log!("player \"{}\" has died", player.name);
+			}
+		}
+	}
+}
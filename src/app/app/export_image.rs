@@ -0,0 +1,30 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+use crate::error::{Error, Result};
+use crate::graphics::MapImage;
+use crate::log::log;
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+impl App {
+	pub(super) fn export_image(&self, path: &Path) -> Result<()> {
+		let scale = self.args.export_scale;
+
+		log!("exporting map image at scale `{scale}` to \"{}\"", path.display());
+
+		let image = MapImage::render(&self.map, self.level.background, scale)
+			.ok_or_else(|| Error::UnwritableImage { path: path.into(), source: "image is too large".into() })?;
+
+		let file = File::create(path)
+			.map_err(|e| Error::UnwritableImage { path: path.into(), source: Box::new(e) })?;
+
+		image
+			.write_png(BufWriter::new(file))
+			.map_err(|e| Error::UnwritableImage { path: path.into(), source: Box::new(e) })?;
+
+		Ok(())
+	}
+}
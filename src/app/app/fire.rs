@@ -0,0 +1,52 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+use crate::log::log;
+use crate::player::Player;
+use crate::projectile::Projectile;
+
+use std::f64::consts::FRAC_1_SQRT_2;
+
+impl App {
+	/// The speed at which shells are fired, in blocks per tick.
+	pub const FIRE_SPEED: f64 = 2.0;
+
+	/// Fires a shell from the given player.
+	///
+	/// Shells are launched from the player's head at an angle of 45 degrees, towards the middle of the map.
+	/// During a match, only the active player may fire, and doing so ends their turn.
+	pub(super) fn fire(&mut self, index: usize) {
+		if self.game_state.is_playing() && self.game_state.active_player() != Some(index) {
+			log!(warning, "only the active player may fire");
+			return;
+		}
+
+		let Some(player) = self.players.iter().nth(index) else {
+			return;
+		};
+
+		if !player.is_spawned || !player.is_alive() {
+			log!(warning, "player \"{}\" is unable to fire", player.name);
+			return;
+		}
+
+		let (x, y) = player.position;
+
+		let direction = if x < f64::from(self.map.width()) / 2.0 { 1.0 } else { -1.0 };
+
+		let speed = Self::FIRE_SPEED * FRAC_1_SQRT_2;
+
+		let projectile = Projectile::new(
+			(x, y + Player::HEIGHT),
+			(direction * speed, speed),
+		);
+
+		log!("player \"{}\" fired from `{:?}`", player.name, projectile.position);
+
+		self.projectiles.push(projectile);
+
+		if self.game_state.is_playing() {
+			self.end_turn();
+		}
+	}
+}
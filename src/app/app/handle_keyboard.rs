@@ -1,11 +1,13 @@
 // Copyright 2022-2025 Gabriel Bjørnager Jensen.
 
-use crate::app::App;
+use crate::app::{App, GameState, KeyAction};
 use crate::log::log;
+use crate::player::{Connexion, Walk};
+use crate::snapshot::Snapshot;
 
 use winit::event::{DeviceId, ElementState, KeyEvent};
 use winit::event_loop::ActiveEventLoop;
-use winit::keyboard::{KeyCode, PhysicalKey};
+use winit::keyboard::PhysicalKey;
 
 impl App {
 	pub(super) fn handle_keyboard(
@@ -19,18 +21,37 @@ impl App {
 			return;
 		}
 
+		let PhysicalKey::Code(code) = event.physical_key else {
+			return;
+		};
+
+		let Some(action) = self.config.keys.action(code) else {
+			return;
+		};
+
+		// Walking lasts for as long as the key is held.
+
+		if matches!(action, KeyAction::WalkLeft | KeyAction::WalkRight) {
+			let walk = match action {
+				KeyAction::WalkLeft  => Walk::Left,
+				KeyAction::WalkRight => Walk::Right,
+
+				_ => unreachable!(),
+			};
+
+			self.handle_walk(walk, event.state);
+			return;
+		}
+
 		if matches!(event.state, ElementState::Released) {
 			return;
 		}
 
-		match event.physical_key {
-			PhysicalKey::Code(
-				| KeyCode::Minus
-				| KeyCode::Equal
-			) => {
-				let off: i16 = match event.physical_key {
-					PhysicalKey::Code(KeyCode::Minus) => -0x1,
-					PhysicalKey::Code(KeyCode::Equal) =>  0x1,
+		match action {
+			KeyAction::IncreaseTps | KeyAction::DecreaseTps => {
+				let off: i16 = match action {
+					KeyAction::DecreaseTps => -0x1,
+					KeyAction::IncreaseTps =>  0x1,
 
 					_ => unreachable!(),
 				};
@@ -46,7 +67,7 @@ impl App {
 				self.preset.tps = tps;
 			}
 
-			PhysicalKey::Code(KeyCode::Escape) => {
+			KeyAction::Pause => {
 				self.is_paused = !self.is_paused;
 
 				if self.is_paused {
@@ -56,13 +77,104 @@ impl App {
 				}
 			}
 
-			PhysicalKey::Code(KeyCode::KeyQ)
-			if self.is_paused
-			=> {
-				event_loop.exit();
+			KeyAction::ExportImage => {
+				let path = Self::new_file_path(&self.dirs.export_dir(), "png");
+
+				if let Err(e) = self.export_image(&path) {
+					log!(error, "{e}");
+				}
+			}
+
+			KeyAction::SaveSnapshot => {
+				let path = Self::new_file_path(&self.dirs.snapshot_dir(), Snapshot::EXTENSION);
+
+				if let Err(e) = self.save_snapshot(&path) {
+					log!(error, "{e}");
+				}
+			}
+
+			KeyAction::NextLevel => {
+				self.select_level(0x1);
+			}
+
+			KeyAction::PreviousLevel => {
+				self.select_level(-0x1);
+			}
+
+			KeyAction::ReloadLevel => {
+				self.reload_level();
+			}
+
+			KeyAction::Quit => {
+				if self.is_paused {
+					event_loop.exit();
+				}
+			}
+
+			KeyAction::StartGame => {
+				self.start_game();
+			}
+
+			KeyAction::EndTurn => {
+				self.end_turn();
 			}
 
-			_ => { }
+			KeyAction::Fire => {
+				if let Some(index) = self.controlled_player() {
+					self.fire(index);
+				}
+			}
+
+			KeyAction::WalkLeft | KeyAction::WalkRight => unreachable!(),
+		}
+	}
+
+	/// Starts or stops the local player walking.
+	///
+	/// See [`controlled_player`](Self::controlled_player) for which player is moved.
+	fn handle_walk(&mut self, walk: Walk, state: ElementState) {
+		let Some(index) = self.controlled_player() else {
+			return;
+		};
+
+		let Some(player) = self.players.iter_mut().nth(index) else {
+			return;
+		};
+
+		match state {
+			ElementState::Pressed => player.walk = walk,
+
+			// Only stop if the player is still walking in
+			// the released direction, as the other key may
+			// have been pressed in the meantime.
+			ElementState::Released => {
+				if player.walk == walk {
+					player.walk = Walk::Still;
+				}
+			}
 		}
 	}
+
+	/// Gets the index of the local player that is controlled by the keyboard.
+	///
+	/// During a match, this is the active player, and only if they are local.
+	/// Otherwise, it is the first local player.
+	#[must_use]
+	fn controlled_player(&self) -> Option<usize> {
+		let mut players = self
+			.players
+			.iter()
+			.enumerate()
+			.filter(|&(_, player)| matches!(player.connexion, Connexion::Local));
+
+		let player = if self.game_state.is_playing() {
+			let active = self.game_state.active_player();
+
+			players.find(|&(index, _)| Some(index) == active)
+		} else {
+			players.next()
+		};
+
+		player.map(|(index, _)| index)
+	}
 }
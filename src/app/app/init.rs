@@ -3,23 +3,80 @@
 use crate::app::{App, UserEvent};
 use crate::error::Result;
 use crate::log::log;
-
-use std::env::args;
+use crate::player::Player;
 
 impl App {
 	pub(super) fn init(&mut self) -> Result<()> {
 		self.set_terminate_handler()?;
 
-		if let Some(level) = args().nth(0x1) {
-			let level = self.load_level(&level)?;
-			self.level = level;
+		self.config = self.load_config()?;
+
+		if let Some(ref path) = self.args.snapshot {
+			// Snapshots contain their own level, preset,
+			// map, and generator state, so use these in-
+			// stead of generating a new map.
+			//
+			// Note that the continuation is not exactly
+			// that of an uninterrupted run: Block ages and
+			// column states are reset, sticky clusters are
+			// relabelled, and respawning players consumes
+			// the restored generator.
+
+			let snapshot = self.load_snapshot(path)?;
+
+			snapshot.level.validate()?;
+
+			self.preset = snapshot.preset;
+			self.level  = snapshot.level;
+			self.map    = snapshot.map;
+			self.rng    = snapshot.rng;
+
+			self.rules = self.load_rules()?;
+		} else {
+			// Prefer the level from the command line over
+			// the configured one.
+
+			let level = self.args.level.as_ref().or(self.config.level.as_ref());
+
+			if let Some(level) = level.cloned() {
+				self.level    = self.load_level(&level)?;
+				self.level_id = Some(level);
+
+				self.level_modified = self.level_file_modified();
+			}
+
+			self.rules = self.load_rules()?;
+
+			if let Some(seed) = self.args.seed {
+				log!(note, "using seed `{seed}` from command line");
+
+				self.preset.seed = seed;
+			}
+
+			if let Some(map_size) = self.args.map_size {
+				log!(note, "using map size `{map_size:?}` from command line");
+
+				self.preset.map_size = map_size;
+			}
+
+			self.regenerate_level();
+		}
+
+		if let Some(tps) = self.args.tps {
+			log!(note, "using tps `{tps}` from command line");
+
+			self.preset.tps = tps;
 		}
 
 		self.players.clear();
 
-		self.players.insert(Default::default());
+		self.players.insert(Player {
+			name:  self.config.player_name.to_string().into(),
+			token: self.config.token,
+			team:  self.config.team,
 
-		self.regenerate_level();
+			..Default::default()
+		});
 
 		self.respawn_players()?;
 
@@ -27,9 +84,16 @@ impl App {
 	}
 
 	fn set_terminate_handler(&self) -> Result<()> {
+		// We have no event loop to notify whilst head-
+		// less, so just keep the default handler.
+
+		let Some(ref event_loop) = self.event_loop_proxy else {
+			return Ok(());
+		};
+
 		log!(debug, "setting terminate handler");
 
-		let event_loop = self.event_loop_proxy.clone();
+		let event_loop = event_loop.clone();
 
 		ctrlc::set_handler(move || {
 			event_loop
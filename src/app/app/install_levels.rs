@@ -0,0 +1,134 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+use crate::level::BundledLevel;
+use crate::log::log;
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, write};
+
+#[derive(Debug, Deserialize)]
+struct RevisionHelper {
+	pub level: RevisionLevelHelper,
+}
+
+#[derive(Debug, Deserialize)]
+struct RevisionLevelHelper {
+	pub revision: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+struct InstalledHelper {
+	/// The hashes of the installed levels, by name.
+	pub levels: BTreeMap<String, String>,
+}
+
+impl App {
+	/// Installs the bundled levels into the level directory.
+	///
+	/// Levels are only written if they are missing or if their revision is older than the bundled one.
+	/// Levels without a revision are considered user levels and are never overwritten.
+	///
+	/// The contents of installed levels are recorded in the cache directory.
+	/// Installed levels that have since been edited -- or whose record is missing -- are left untouched, with a warning, instead of being updated.
+	pub(super) fn install_levels(&self) {
+		let dir = self.dirs.level_dir();
+
+		let installed_path = self.dirs.installed_levels_path();
+
+		let mut installed = read_to_string(&installed_path)
+			.ok()
+			.and_then(|file| toml::from_str::<InstalledHelper>(&file).ok())
+			.unwrap_or_default();
+
+		let mut is_installed_changed = false;
+
+		for level in BundledLevel::ALL {
+			let path = {
+				let mut path = dir.join(level.name);
+				path.set_extension("toml");
+
+				path
+			};
+
+			let hash = content_hash(level.source);
+
+			if path.exists() {
+				let Ok(file) = read_to_string(&path) else {
+					log!(warning, "unable to read level at \"{}\"", path.display());
+					continue;
+				};
+
+				let current_hash = content_hash(&file);
+
+				// Also record levels that are identical to the
+				// bundled ones, e.g. if the record was lost.
+
+				if current_hash == hash {
+					if installed.levels.get(level.name) != Some(&hash) {
+						installed.levels.insert(level.name.into(), hash);
+						is_installed_changed = true;
+					}
+
+					log!(debug, "level at \"{}\" is up to date", path.display());
+					continue;
+				}
+
+				let revision = toml::from_str::<RevisionHelper>(&file)
+					.ok()
+					.and_then(|helper| helper.level.revision);
+
+				let Some(revision) = revision else {
+					log!(note, "leaving user level at \"{}\" untouched", path.display());
+					continue;
+				};
+
+				if revision >= level.revision {
+					log!(debug, "level at \"{}\" is up to date", path.display());
+					continue;
+				}
+
+				if installed.levels.get(level.name) != Some(&current_hash) {
+					log!(warning, "level at \"{}\" has been edited and is not updated to revision `{}`", path.display(), level.revision);
+					continue;
+				}
+
+				log!(note, "updating level at \"{}\" from revision `{revision}` to `{}`", path.display(), level.revision);
+			}
+
+			log!(debug, "installing level to \"{}\"", path.display());
+
+			if let Err(e) = write(&path, level.source) {
+				log!(warning, "unable to install level at \"{}\": {e}", path.display());
+				continue;
+			}
+
+			installed.levels.insert(level.name.into(), hash);
+			is_installed_changed = true;
+		}
+
+		if !is_installed_changed {
+			return;
+		}
+
+		let source = toml::to_string(&installed).expect("unable to serialise installed levels");
+
+		if let Err(e) = write(&installed_path, source) {
+			log!(warning, "unable to record installed levels at \"{}\": {e}", installed_path.display());
+		}
+	}
+}
+
+/// Hashes the contents of a level file.
+///
+/// This uses 64-bit FNV-1a, which -- unlike the hashers of the standard library -- is stable across versions.
+#[must_use]
+fn content_hash(source: &str) -> String {
+	let hash = source
+		.bytes()
+		.fold(0xCBF2_9CE4_8422_2325u64, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01B3));
+
+	format!("{hash:016X}")
+}
@@ -0,0 +1,25 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+use crate::error::Result;
+use crate::level::LevelInfo;
+use crate::log::log;
+
+impl App {
+	pub(super) fn list_levels(&self) -> Result<()> {
+		let levels = LevelInfo::discover(&self.dirs.level_dir());
+
+		for level in &levels {
+			let origin = if level.is_builtin { " (built-in)" } else { "" };
+
+			println!("{}{origin}", level.id);
+			println!("    \"{}\" by {}: {}", level.name, level.creatour, level.description);
+		}
+
+		for ids in LevelInfo::find_duplicates(&levels) {
+			log!(warning, "levels {ids:?} have the same name");
+		}
+
+		Ok(())
+	}
+}
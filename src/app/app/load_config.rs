@@ -0,0 +1,118 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::{App, Config, KeyBindings};
+use crate::error::{Error, Result};
+use crate::log::log;
+use crate::message::{LobbyName, LobbyPassword};
+use crate::player::{Team, Token};
+
+use serde::{Deserialize, Serialize};
+use std::fs::{read_to_string, write};
+use std::net::SocketAddr;
+use std::path::Path;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub(super) struct ConfigHelper {
+	pub player:  ConfigPlayerHelper,
+	pub game:    ConfigGameHelper,
+	pub window:  ConfigWindowHelper,
+	pub keys:    KeyBindings,
+	pub network: ConfigNetworkHelper,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub(super) struct ConfigPlayerHelper {
+	pub name:  Option<String>,
+	pub token: Option<Token>,
+	pub team:  Option<Team>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub(super) struct ConfigGameHelper {
+	pub level: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub(super) struct ConfigWindowHelper {
+	pub size:       Option<(u32, u32)>,
+	pub fullscreen: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub(super) struct ConfigNetworkHelper {
+	pub addr:     Option<SocketAddr>,
+	pub lobby:    Option<String>,
+	pub password: Option<String>,
+}
+
+impl App {
+	pub(super) fn load_config(&self) -> Result<Config> {
+		let path = self.dirs.config_path();
+
+		if !path.exists() {
+			log!("writing default config to \"{}\"", path.display());
+
+			if let Err(e) = write(&path, Config::DEFAULT_FILE) {
+				log!(warning, "unable to write default config: {e}");
+			}
+
+			return Ok(Default::default());
+		}
+
+		log!(debug, "loading config at \"{}\"", path.display());
+
+		let source = read_to_string(&path)
+			.map_err(|e| Error::UnknownConfig { path: path.clone().into(), source: Box::new(e) })?;
+
+		let config = parse_config(&source, &path)?;
+
+		log!(note, "loaded config:\n```\n{config:#?}\n```");
+
+		Ok(config)
+	}
+}
+
+/// Parses a configuration.
+///
+/// Omitted fields keep their default values.
+/// The path is only used for reporting errors.
+pub(super) fn parse_config(source: &str, path: &Path) -> Result<Config> {
+	let helper = toml::from_str::<ConfigHelper>(source)
+		.map_err(|e| Error::UnknownConfig { path: path.into(), source: Box::new(e) })?;
+
+	let mut config = Config::default();
+
+	if let Some(name)  = helper.player.name  { config.player_name = name.into() }
+	if let Some(token) = helper.player.token { config.token       = token }
+	if let Some(team)  = helper.player.team  { config.team        = team }
+
+	config.level = helper.game.level.map(Into::into);
+
+	if let Some(size)       = helper.window.size       { config.window.size          = size }
+	if let Some(fullscreen) = helper.window.fullscreen { config.window.is_fullscreen = fullscreen }
+
+	config.keys = helper.keys;
+
+	config.addr = helper.network.addr;
+
+	if let Some(lobby) = helper.network.lobby {
+		config.lobby = lobby.parse::<LobbyName>().map_err(|e| Error::UnknownConfig {
+			path:   path.into(),
+			source: format!("invalid lobby name \"{lobby}\": {e}").into(),
+		})?;
+	}
+
+	if let Some(password) = helper.network.password {
+		config.password = password.parse::<LobbyPassword>().map_err(|e| Error::UnknownConfig {
+			path:   path.into(),
+			source: format!("invalid lobby password: {e}").into(),
+		})?;
+	}
+
+	Ok(config)
+}
@@ -2,131 +2,55 @@
 
 use crate::app::App;
 use crate::error::{Error, Result};
-use crate::level::{Chunk, Layer, Level};
+use crate::level::Level;
 use crate::log::log;
 
-use serde::Deserialize;
 use std::fs::read_to_string;
+use std::path::Path;
 
-#[derive(Debug, Deserialize)]
-struct LevelHelper {
-	pub level: LevelLevelHelper,
-
-	pub chunk: Vec<LevelChunkHelper>,
-}
-
-#[derive(Debug, Deserialize)]
-struct LevelLevelHelper {
-	pub name:        String,
-	pub creatour:    String,
-	pub description: String,
-
-	pub background: String,
-}
+impl App {
+	pub(super) fn load_level(&self, name: &str) -> Result<Level> {
+		log!("loading level \"{name}\"");
 
-#[derive(Debug, Deserialize)]
-struct LevelChunkHelper {
-	pub width: f64,
+		if !Level::is_valid_id(name) {
+			return Err(Error::UnknownLevel {
+				path:   Path::new(name).into(),
+				source: format!("`{name}` is not a valid level id").into(),
+			});
+		}
 
-	pub is_spawnable: bool,
+		let path = {
+			let mut path = self.dirs.level_dir();
 
-	pub layer: Vec<LevelChunkLayerHelper>,
-}
+			path.push(name);
+			path.set_extension("toml");
 
-#[derive(Debug, Deserialize)]
-struct LevelChunkLayerHelper {
-	pub height:   f64,
-	pub material: String,
-}
+			path
+		};
 
-impl App {
-	pub(super) fn load_level(&self, name: &str) -> Result<Level> {
-		// Firstly check if the level is a built-in.
+		// Prefer level files over built-ins, so that the
+		// installed copies of the built-in levels may be
+		// edited.
 
-		log!("loading level \"{name}\"");
+		let builtin = if path.exists() { None } else { Level::load_builtin(name) };
 
-		let level = if let Some(level) = Level::load_builtin(name) {
+		let level = if let Some(level) = builtin {
 			log!(debug, "loading built-in level \"{name}\"");
 
 			level
 		} else {
-			log!(note, "level is not a built-in");
-
-			let path = {
-				let mut path = self.data_dir.clone();
-
-				path.push("level");
-				path.push(name);
-				path.set_extension("toml");
-
-				path
-			};
-
 			log!(debug, "loading level at \"{}\"", path.display());
 
 			let file = read_to_string(&path)
 				.map_err(|e| Error::UnknownLevel { path: path.clone().into(), source: Box::new(e) })?;
 
-			let parse_chunk_layer = |helper: LevelChunkLayerHelper| -> Result<Layer> {
-				let material = helper
-					.material
-					.parse()
-					.map_err(|e| Error::UnknownLevel { path: path.clone().into(), source: Box::new(e) })?;
-
-				let layer = Layer {
-					height:   helper.height,
-					material,
-				};
-
-				Ok(layer)
-			};
-
-			let parse_chunk = |helper: LevelChunkHelper| -> Result<Chunk> {
-				let layers = helper
-					.layer
-					.into_iter()
-					.map(parse_chunk_layer)
-					.collect::<Result<_>>()?;
-
-				let chunk = Chunk {
-					width: helper.width,
-
-					is_spawnable: helper.is_spawnable,
-
-					layers,
-				};
-
-				Ok(chunk)
-			};
-
-			let helper = toml::from_str::<LevelHelper>(&file)
-				.map_err(|e| Error::UnknownLevel { path: path.clone().into(), source: Box::new(e) })?;
-
-			let chunks = helper
-				.chunk
-				.into_iter()
-				.map(parse_chunk)
-				.collect::<Result<_>>()?;
-
-			let background = helper
-				.level
-				.background
-				.parse()
-				.map_err(|e| Error::UnknownLevel { path: path.clone().into(), source: Box::new(e) })?;
-
-			Level {
-				name:        helper.level.name.into(),
-				creatour:    helper.level.creatour.into(),
-				description: helper.level.description.into(),
-
-				background,
-
-				chunks,
-			}
+			Level::from_toml(&file, &path)?
 		};
 
 		log!(note, "loaded level:\n```\n{level:#?}\n```");
 
+		level.validate()?;
+
 		Ok(level)
 	}
 }
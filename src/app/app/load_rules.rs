@@ -0,0 +1,51 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+use crate::error::{Error, Result};
+use crate::log::log;
+use crate::rule::RuleSet;
+
+use std::fs::read_to_string;
+
+impl App {
+	pub(super) fn load_rules(&self) -> Result<RuleSet> {
+		// Levels may use their own rules. Otherwise, use
+		// the user's rules if they exist, or the built-
+		// ins if they do not.
+
+		let path = if let Some(ref name) = self.level.rules {
+			// The name has been checked when the level was
+			// validated, and so cannot escape the rules
+			// directory.
+
+			let mut path = self.dirs.rule_dir();
+
+			path.push(&**name);
+			path.set_extension("toml");
+
+			path
+		} else {
+			let path = self.dirs.rules_path();
+
+			if !path.exists() {
+				log!(debug, "using built-in rules");
+
+				return Ok(Default::default());
+			}
+
+			path
+		};
+
+		log!(debug, "loading rules at \"{}\"", path.display());
+
+		let source = read_to_string(&path)
+			.map_err(|e| Error::UnknownRules { path: path.clone().into(), source: Box::new(e) })?;
+
+		let rules = RuleSet::parse(&source)
+			.map_err(|e| Error::UnknownRules { path: path.into(), source: e })?;
+
+		log!(note, "loaded rules:\n```\n{rules:#?}\n```");
+
+		Ok(rules)
+	}
+}
@@ -0,0 +1,25 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+use crate::error::{Error, Result};
+use crate::log::log;
+use crate::snapshot::Snapshot;
+
+use std::fs::read;
+use std::path::Path;
+
+impl App {
+	pub(super) fn load_snapshot(&self, path: &Path) -> Result<Snapshot> {
+		log!("loading snapshot at \"{}\"", path.display());
+
+		let data = read(path)
+			.map_err(|e| Error::UnknownSnapshot { path: path.into(), source: Box::new(e) })?;
+
+		let snapshot = Snapshot::decode(&data)
+			.map_err(|e| Error::UnknownSnapshot { path: path.into(), source: Box::new(e) })?;
+
+		log!(note, "snapshot is of level \"{}\" with seed `{}`", snapshot.level.name, snapshot.preset.seed);
+
+		Ok(snapshot)
+	}
+}
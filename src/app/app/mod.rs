@@ -1,44 +1,75 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
 mod application_handler;
+mod check_levels;
+mod dump_level;
+mod explode;
+mod export_image;
+mod fire;
 mod handle_keyboard;
 mod handle_mouse_wheel;
 mod init;
+mod install_levels;
+mod list_levels;
+mod load_config;
 mod load_level;
+mod load_rules;
+mod load_snapshot;
 mod main;
+mod new_file_path;
+mod poll_level_file;
 mod print_welcome_message;
 mod regenerate_level;
 mod respawn_players;
 mod run;
+mod run_headless;
+mod run_render;
+mod save_config;
+mod save_snapshot;
+mod select_level;
 mod tick;
+mod tick_game;
+mod tick_players;
+mod tick_projectiles;
+mod validate_level;
 
-use crate::app::{Config, UserEvent};
+use crate::app::{Args, Config, Dirs, GameState, Side, UserEvent};
 use crate::graphics::GraphicsContext;
 use crate::level::Level;
-use crate::map::Map;
+use crate::map::{Map, StickyClusters};
 use crate::player::PlayerList;
 use crate::preset::Preset;
+use crate::projectile::{Ballistics, Projectile};
+use crate::rule::RuleSet;
 
-use std::path::PathBuf;
-use std::time::Instant;
+use rand_chacha::ChaCha8Rng;
+use std::time::{Instant, SystemTime};
 use winit::event::Modifiers;
 use winit::event_loop::EventLoopProxy;
 
 #[derive(Debug)]
 pub struct App {
-	event_loop_proxy: EventLoopProxy<UserEvent>,
+	event_loop_proxy: Option<EventLoopProxy<UserEvent>>,
 
 	graphics_context: GraphicsContext,
 
 	keyboard_modifiers: Modifiers,
 
-	data_dir: PathBuf,
+	args:     Args,
+	dirs:     Dirs,
 	config:   Config,
 	preset:   Preset,
 	level:    Level,
+	rules:    RuleSet,
 
-	map:     Map,
-	players: PlayerList,
+	map:             Map,
+	sticky_clusters: StickyClusters,
+	players:         PlayerList,
+	projectiles:     Vec<Projectile>,
+	ballistics:      Ballistics,
+
+	rng:          ChaCha8Rng,
+	thread_count: usize,
 
 	raw_view_scale: f64,
 
@@ -48,6 +79,27 @@ pub struct App {
 	next_tick: Instant,
 
 	is_paused: bool,
+
+	game_state: GameState,
+
+	/// The current round of the match, counting from one.
+	round: u8,
+
+	/// The amount of sides that entered the current round.
+	round_sides: usize,
+
+	/// The winning side of each finished round, if not drawn.
+	round_winners: Vec<Option<Side>>,
+
+	/// Denotes whether the config has changed since it was loaded.
+	is_config_changed: bool,
+
+	/// The identifier of the loaded level, if loaded by name.
+	level_id: Option<Box<str>>,
+
+	/// The modification time of the loaded level file, if any.
+	level_modified:  Option<SystemTime>,
+	next_level_poll: Instant,
 }
 
 impl App {
@@ -57,7 +109,13 @@ impl App {
 	#[inline]
 	#[track_caller]
 	fn create_user_event(&self, event: UserEvent) {
-		if let Err(e) = self.event_loop_proxy.send_event(event) {
+		// We do not have an event loop whilst headless.
+
+		let Some(ref event_loop_proxy) = self.event_loop_proxy else {
+			return;
+		};
+
+		if let Err(e) = event_loop_proxy.send_event(event) {
 			panic!("unable to create event: {e}");
 		}
 	}
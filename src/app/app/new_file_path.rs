@@ -0,0 +1,39 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+impl App {
+	/// Gets the path for a new file in the given directory.
+	///
+	/// Files are named after the current time.
+	/// If a file of that name already exists -- e.g. if multiple files are made in the same second -- then a counter is appended, so that earlier files are not overwritten.
+	#[must_use]
+	pub(super) fn new_file_path(dir: &Path, extension: &str) -> PathBuf {
+		let time = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.unwrap_or_default()
+			.as_secs();
+
+		let mut counter = 0x0u32;
+
+		loop {
+			let name = if counter == 0x0 {
+				time.to_string()
+			} else {
+				format!("{time}-{counter}")
+			};
+
+			let mut path = dir.join(name);
+			path.set_extension(extension);
+
+			if !path.exists() {
+				break path;
+			}
+
+			counter += 0x1;
+		}
+	}
+}
@@ -0,0 +1,92 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::{App, UserEvent};
+use crate::level::Level;
+use crate::log::log;
+
+use std::fs::{metadata, read_to_string};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+impl App {
+	/// The interval between checks of the level file.
+	pub const LEVEL_POLL_INTERVAL: Duration = Duration::from_secs(0x1);
+
+	/// Reloads the level if its file -- or its bitmap -- has been modified.
+	///
+	/// Levels that were not loaded from a file are never reloaded.
+	pub(super) fn poll_level_file(&mut self) {
+		let Some(modified) = self.level_file_modified() else {
+			return;
+		};
+
+		if self.level_modified.is_some_and(|previous| previous >= modified) {
+			return;
+		}
+
+		log!("level file has changed");
+
+		// Remember the new time even if reloading fails,
+		// so that errors are only reported once per edit.
+
+		self.level_modified = Some(modified);
+
+		self.reload_level();
+	}
+
+	/// Reloads and regenerates the current level.
+	///
+	/// Unlike [`switch_level`](Self::switch_level), the game is kept as is, and players keep their positions.
+	/// Only players that end up inside terrain are moved.
+	/// Failures are logged and leave the current level in place.
+	pub(super) fn reload_level(&mut self) {
+		let Some(id) = self.level_id.clone() else {
+			log!(warning, "level was not loaded by name and cannot be reloaded");
+			return;
+		};
+
+		log!("reloading level \"{id}\"");
+
+		if !self.replace_level(id) {
+			return;
+		}
+
+		self.regenerate_level();
+
+		self.replace_buried_players();
+
+		self.create_user_event(UserEvent::RedrawMap);
+	}
+
+	/// Gets the latest modification time of the loaded level's file and of its bitmap, if any.
+	#[must_use]
+	pub(super) fn level_file_modified(&self) -> Option<SystemTime> {
+		let path = self.level_file_path()?;
+
+		let modified = |path: &Path| metadata(path).and_then(|metadata| metadata.modified()).ok();
+
+		let level_modified = modified(&path)?;
+
+		let bitmap_modified = read_to_string(&path)
+			.ok()
+			.and_then(|source| Level::bitmap_file(&source, &path))
+			.and_then(|path| modified(&path));
+
+		Some(bitmap_modified.map_or(level_modified, |bitmap_modified| level_modified.max(bitmap_modified)))
+	}
+
+	/// Gets the path of the loaded level's file.
+	///
+	/// This is [`None`] if the level is a built-in or otherwise not loaded from a file.
+	#[must_use]
+	fn level_file_path(&self) -> Option<PathBuf> {
+		let id = self.level_id.as_ref()?;
+
+		let mut path = self.dirs.level_dir();
+
+		path.push(&**id);
+		path.set_extension("toml");
+
+		path.exists().then_some(path)
+	}
+}
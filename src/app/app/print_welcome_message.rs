@@ -1,10 +1,15 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
-use crate::app::App;
+use crate::app::{App, KeyBindings};
 use crate::version::Version;
 
 impl App {
-	pub(super) fn print_welcome_message() {
+	/// Prints the welcome message, including the configured controls.
+	pub(super) fn print_welcome_message(&self) {
+		let keys = &self.config.keys;
+
+		let key = KeyBindings::key_name;
+
 		eprintln!();
 		eprintln!("\u{001B}[001mYOU HAVE NOW HIT BEDROCK!\u{001B}[022m");
 		eprintln!("\u{001B}[002mbedrock-{}\u{001B}[022m", Version::CURRENT);
@@ -12,8 +17,18 @@ impl App {
 		eprintln!("\u{001B}[002mCopyright \u{00A9} 2025 Gabriel Bj\u{00F8}rnager Jensen.\u{001B}[022m");
 		eprintln!();
 		eprintln!("Controls (en-gb):");
-		eprintln!("  esc                : pause / unpause");
-		eprintln!("  q                  : quit (whilst paused)");
+		eprintln!("  {:<18} : pause / unpause", key(keys.pause));
+		eprintln!("  {:<18} : quit (whilst paused)", key(keys.quit));
+		eprintln!("  {:<18} : export map image", key(keys.export_image));
+		eprintln!("  {:<18} : save snapshot", key(keys.save_snapshot));
+		eprintln!("  {:<18} : previous level", key(keys.previous_level));
+		eprintln!("  {:<18} : next level", key(keys.next_level));
+		eprintln!("  {:<18} : reload level", key(keys.reload_level));
+		eprintln!("  {:<18} : walk left", key(keys.walk_left));
+		eprintln!("  {:<18} : walk right", key(keys.walk_right));
+		eprintln!("  {:<18} : start game", key(keys.start_game));
+		eprintln!("  {:<18} : end turn", key(keys.end_turn));
+		eprintln!("  {:<18} : fire", key(keys.fire));
 		eprintln!();
 		eprintln!("  mwheelup           : pan right");
 		eprintln!("  mwheeldown         : pan left");
@@ -24,8 +39,8 @@ impl App {
 		eprintln!("  ctrl + mwheelup    : zoom in");
 		eprintln!("  ctrl + mwheeldown  : zoom out");
 		eprintln!();
-		eprintln!("  plus               : increas tps");
-		eprintln!("  hyphen             : decrease tps");
+		eprintln!("  {:<18} : increase tps", key(keys.increase_tps));
+		eprintln!("  {:<18} : decrease tps", key(keys.decrease_tps));
 		eprintln!();
 	}
 }
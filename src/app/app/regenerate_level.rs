@@ -1,12 +1,14 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
 use crate::app::App;
-use crate::level::{Block, Chunk, Layer, Level, Material};
+use crate::level::{Bitmap, Block, Chunk, Layer, Level, Material};
 use crate::log::log;
 use crate::map::{Map, MapSize};
 
-use rand::random;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use std::borrow::BorrowMut;
+use std::ops::Range;
 
 trait Segment {
 	#[must_use]
@@ -74,8 +76,10 @@ where
 		if coordinate >= self.next {
 			let segment = self.iter.next()?;
 
-			assert!(segment.size() >= 0.0);
-			assert!(segment.size() <= 1.0);
+			// Levels are validated when loaded.
+
+			debug_assert!(segment.size() >= 0.0);
+			debug_assert!(segment.size() <= 1.0);
 
 			let width = (self.total_size * segment.size()) as u32;
 
@@ -91,31 +95,62 @@ where
 }
 
 impl App {
+	/// Gets the seed of the current round.
+	///
+	/// Each round of a match is played on its own map, derived from the preset's seed and the round number.
+	/// Outside of matches, this is just the preset's seed.
+	#[must_use]
+	pub(super) fn round_seed(&self) -> u64 {
+		// Round zero denotes the lobby.
+
+		self.preset.seed ^ u64::from(self.round).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+	}
+
 	pub(super) fn regenerate_level(&mut self) {
 		log!("generating level \"{}\"", self.level.name);
 
 		log!(note, "config is: {:?}", self.config);
 		log!(note, "level is: {:?}", self.level);
 
-		assert!(self.level.chunks.len() <= u8::MAX as usize);
+		debug_assert!(self.level.chunks.len() <= u8::MAX as usize);
+
+		// Reseed the game generator so that the same seed
+		// always yields the same map and simulation.
+
+		let seed = self.round_seed();
+
+		log!(debug, "seeding generator with `{seed:#018X}`");
+
+		self.rng = ChaCha8Rng::seed_from_u64(seed);
 
 		self.map.resize(self.preset.map_size);
 
-		roll_seeds(&mut self.map);
+		self.sticky_clusters = Default::default();
 
-		generate_columns(
-			self.map.columns_mut(),
-			&self.level,
-			self.preset.map_size,
-		);
+		roll_seeds(&mut self.map, &mut self.rng);
+
+		if let Some(ref bitmap) = self.level.bitmap {
+			generate_bitmap(
+				self.map.columns_mut(),
+				bitmap,
+				self.preset.map_size,
+			);
+		} else {
+			generate_columns(
+				self.map.columns_mut(),
+				&self.level,
+				self.preset.map_size,
+				seed,
+			);
+		}
 
 		fill_bedrock(&mut self.map);
 	}
 }
 
-fn roll_seeds(map: &mut Map) {
+fn roll_seeds<R: Rng>(map: &mut Map, rng: &mut R) {
 	for cell in map.columns_mut().flat_map(<[_]>::iter_mut) {
-		let seed = random();
+		let seed = rng.random();
 		cell.set_seed(seed);
 	}
 }
@@ -124,11 +159,11 @@ fn generate_columns<I: IntoIterator<Item: BorrowMut<[Block]>>>(
 	columns:  I,
 	level:    &Level,
 	map_size: MapSize,
+	seed:     u64,
 ) {
-	let mut chunks = SegmentTracker::new(
-		&*level.chunks,
-		map_size.width(),
-	);
+	let (width, height) = map_size.get();
+
+	let bounds = level.chunk_bounds(width);
 
 	let columns = columns
 		.into_iter()
@@ -136,13 +171,40 @@ fn generate_columns<I: IntoIterator<Item: BorrowMut<[Block]>>>(
 		.map(|(x, column)| (x as u32, column));
 
 	for (x, mut column) in columns {
-		let Some(chunk) = chunks.next(x) else {
+		let Some(index) = bounds.iter().position(|bounds| bounds.contains(&x)) else {
 			break;
 		};
 
+		let chunk = &level.chunks[index];
+
+		// Sample the centre of each column.
+
+		let centre = f64::from(x) + 0.5;
+
+		let layers = {
+			let layers = column_layers(chunk, index, seed, centre / f64::from(width));
+
+			let total: f64 = layers.iter().map(|layer| layer.height).sum();
+
+			// Stretch the layers to follow the surface.
+
+			let surface = column_surface(level, &bounds, index, seed, centre, width);
+
+			let scale = if total > 0.0 { surface / total } else { 1.0 };
+
+			layers
+				.into_iter()
+				.map(|layer| Layer {
+					height: (layer.height * scale).clamp(0.0, 1.0),
+
+					..layer
+				})
+				.collect::<Vec<_>>()
+		};
+
 		let mut layers = SegmentTracker::new(
-			&*chunk.layers,
-			map_size.height(),
+			&layers,
+			height,
 		);
 
 		let cells = column
@@ -161,6 +223,137 @@ fn generate_columns<I: IntoIterator<Item: BorrowMut<[Block]>>>(
 	}
 }
 
+/// Gets the layers of a chunk at the given column.
+///
+/// Layer noise is applied to the layer heights.
+#[must_use]
+fn column_layers(chunk: &Chunk, index: usize, seed: u64, x: f64) -> Vec<Layer> {
+	chunk
+		.layers
+		.iter()
+		.enumerate()
+		.map(|(j, layer)| {
+			let offset = layer.noise.map_or(0.0, |noise| noise.sample(noise_seed(seed, index, j + 0x1), x));
+
+			Layer {
+				height: (layer.height + offset).clamp(0.0, 1.0),
+
+				..layer.clone()
+			}
+		})
+		.collect()
+}
+
+/// Gets the surface height of a chunk at the given column, without blending.
+///
+/// This includes both layer and chunk noise.
+#[must_use]
+fn chunk_surface(chunk: &Chunk, index: usize, seed: u64, x: f64) -> f64 {
+	let total: f64 = column_layers(chunk, index, seed, x)
+		.iter()
+		.map(|layer| layer.height)
+		.sum();
+
+	let offset = chunk.noise.map_or(0.0, |noise| noise.sample(noise_seed(seed, index, 0x0), x));
+
+	(total + offset).clamp(0.0, 1.0)
+}
+
+/// Gets the surface height at the given column.
+///
+/// Near the edges of chunks, the surface is interpolated smoothly towards the neighbouring chunks.
+/// The coordinate is in columns.
+#[must_use]
+fn column_surface(
+	level:  &Level,
+	bounds: &[Range<u32>],
+	index:  usize,
+	seed:   u64,
+	x:      f64,
+	width:  u32,
+) -> f64 {
+	let surface = |index: usize| -> f64 {
+		chunk_surface(&level.chunks[index], index, seed, x / f64::from(width))
+	};
+
+	let zone = |index: usize| -> f64 {
+		let columns = bounds[index].end - bounds[index].start;
+
+		f64::from(columns) * level.chunks[index].blend
+	};
+
+	// Blend between the given chunk and the next one.
+
+	let blend = |left: usize| -> Option<f64> {
+		let right = left + 0x1;
+
+		if right >= level.chunks.len() {
+			return None;
+		}
+
+		let edge = f64::from(bounds[left].end);
+
+		let (left_zone, right_zone) = (zone(left), zone(right));
+
+		if !(edge - left_zone..edge + right_zone).contains(&x) {
+			return None;
+		}
+
+		let t = (x - (edge - left_zone)) / (left_zone + right_zone);
+		let t = t * t * t.mul_add(-2.0, 3.0);
+
+		let (start, end) = (surface(left), surface(right));
+
+		Some((end - start).mul_add(t, start))
+	};
+
+	index
+		.checked_sub(0x1)
+		.and_then(blend)
+		.or_else(|| blend(index))
+		.unwrap_or_else(|| surface(index))
+}
+
+/// Derives the noise seed of a chunk or layer.
+///
+/// Layers are numbered from one, with zero denoting the chunk itself.
+#[inline]
+#[must_use]
+fn noise_seed(seed: u64, chunk: usize, layer: usize) -> u64 {
+	seed ^ ((chunk as u64) << 0x20) ^ layer as u64
+}
+
+fn generate_bitmap<I: IntoIterator<Item: BorrowMut<[Block]>>>(
+	columns:  I,
+	bitmap:   &Bitmap,
+	map_size: MapSize,
+) {
+	let (width, height) = map_size.get();
+
+	let columns = columns
+		.into_iter()
+		.enumerate()
+		.map(|(x, column)| (x as u32, column));
+
+	for (x, mut column) in columns {
+		// Sample the centre of each block.
+
+		let x = (f64::from(x) + 0.5) / f64::from(width);
+
+		let cells = column
+			.borrow_mut()
+			.iter_mut()
+			.enumerate()
+			.map(|(y, block)| (y as u32, block));
+
+		for (y, cell) in cells {
+			let y = (f64::from(y) + 0.5) / f64::from(height);
+
+			cell.set_material(bitmap.sample(x, y));
+		}
+	}
+}
+
 fn fill_bedrock(map: &mut Map) {
 	for column in map.columns_mut() {
 		for cell in column.iter_mut().take(0x1) {
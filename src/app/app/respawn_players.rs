@@ -2,38 +2,163 @@
 
 use crate::app::App;
 use crate::error::{Error, Result};
+use crate::level::BlockTags;
 use crate::log::log;
+use crate::map::Map;
+use crate::player::{Player, Walk};
+
+use rand::Rng;
 
 impl App {
+	/// The minimum horizontal distance between players, in blocks.
+	///
+	/// This is only upheld if there is enough room in the spawn chunks.
+	pub const MIN_SPAWN_SPACING: u32 = 0x4;
+
 	pub(super) fn respawn_players(&mut self) -> Result<()> {
 		log!("respawning `{}` player(s)", self.players.len());
 
-		let spawn_chunks: Vec<_> = self
+		let bounds = self.level.chunk_bounds(self.map.width());
+
+		// Find every column in the spawn chunks that has
+		// room for a player.
+
+		let spots: Vec<(u32, u32)> = self
 			.level
 			.chunks
 			.iter()
-			.enumerate()
-			.filter(|&(_, c)| c.is_spawnable)
-			.map(|(i, _)| i)
+			.zip(bounds)
+			.filter(|&(chunk, _)| chunk.is_spawnable)
+			.flat_map(|(_, bounds)| bounds)
+			.filter_map(|x| spawn_height(&self.map, x).map(|y| (x, y)))
 			.collect();
 
 		log!(
 			note,
-			"there is/are `{}` spawn chunk(s) in level \"{}\"",
-			spawn_chunks.len(),
+			"there is/are `{}` spawn column(s) in level \"{}\"",
+			spots.len(),
 			self.level.name,
 		);
 
-		if spawn_chunks.is_empty() {
+		let player_count = self.players.len();
+
+		if player_count == 0x0 {
+			return Ok(());
+		}
+
+		if spots.is_empty() {
 			return Err(Error::MissingSpawnChunk);
 		}
 
-		// TODO
+		if spots.len() < player_count {
+			log!(warning, "not enough spawn columns for `{player_count}` players; some will share a column");
+		}
+
+		// Divide the spots into a slot per player and pick
+		// a random spot near the middle of each slot. This
+		// spaces players evenly apart.
+
+		let slot_len = (spots.len() / player_count).max(0x1);
+
+		let mut taken: Vec<(u32, u32)> = Vec::with_capacity(player_count);
+
+		for i in 0x0..player_count {
+			let start = (i * slot_len).min(spots.len() - 0x1);
+			let end   = (start + slot_len).min(spots.len());
+
+			let slot = &spots[start..end];
+
+			let jitter = slot.len() / 0x4;
+
+			let preferred = self.rng.random_range(slot.len() / 0x2 - jitter..=slot.len() / 0x2 + jitter).min(slot.len() - 0x1);
+
+			// Prefer spots that are spaced apart, but fall
+			// back to any free spot if the slot is cramped.
+
+			let is_spaced = |x: u32| taken.iter().all(|&(other, _)| x.abs_diff(other) >= Self::MIN_SPAWN_SPACING);
+			let is_free   = |x: u32| taken.iter().all(|&(other, _)| x != other);
+
+			let spot = nearest(slot, preferred, |&(x, _)| is_spaced(x))
+				.or_else(|| nearest(&spots, start + preferred, |&(x, _)| is_free(x)))
+				.unwrap_or(slot[preferred]);
+
+			taken.push(spot);
+		}
+
+		for (player, (x, y)) in self.players.iter_mut().zip(taken) {
+			player.position   = (f64::from(x) + 0.5, f64::from(y));
+			player.is_spawned = true;
+
+			player.health     = Player::MAX_HEALTH;
+			player.walk       = Walk::Still;
+			player.fall_speed = 0.0;
+			player.fall_start = None;
 
-		for player in &self.players {
-			log!("spawning player \"{}\"", player.name);
+			log!("spawning player \"{}\" at `{:?}`", player.name, player.position);
 		}
 
 		Ok(())
 	}
+
+	/// Moves players that are inside terrain to the top of their columns.
+	///
+	/// Players in unsuitable columns -- see [`spawn_height`] -- are left in place and climb out by themselves.
+	pub(super) fn replace_buried_players(&mut self) {
+		let width = self.map.width();
+
+		for player in self.players.iter_mut() {
+			if !player.is_buried(&self.map) {
+				continue;
+			}
+
+			let x = (player.position.0 as u32).min(width.saturating_sub(0x1));
+
+			let Some(y) = spawn_height(&self.map, x) else {
+				log!(warning, "player \"{}\" is buried and could not be moved", player.name);
+				continue;
+			};
+
+			player.position   = (f64::from(x) + 0.5, f64::from(y));
+			player.fall_speed = 0.0;
+			player.fall_start = None;
+
+			log!("moving buried player \"{}\" to `{:?}`", player.name, player.position);
+		}
+	}
+}
+
+/// Gets the height at which a player would stand in a column.
+///
+/// This is just above the first non-empty block from the top.
+/// If this block is a liquid, or if there is no room above it, then the column is unsuitable and [`None`] is returned.
+#[must_use]
+fn spawn_height(map: &Map, x: u32) -> Option<u32> {
+	let centre = f64::from(x) + 0.5;
+
+	let y = (0x0..map.height()).rev().find(|&y| {
+		map
+			.sample(centre, f64::from(y) + 0.5)
+			.is_some_and(|block| !block.tags().contains(BlockTags::EMPTY))
+	})?;
+
+	let block = map.sample(centre, f64::from(y) + 0.5)?;
+
+	if block.tags().contains(BlockTags::LIQUID) {
+		return None;
+	}
+
+	let y = y + 0x1;
+
+	(y < map.height()).then_some(y)
+}
+
+/// Finds the spot nearest to the given index that satisfies a predicate.
+#[must_use]
+fn nearest<F: Fn(&(u32, u32)) -> bool>(spots: &[(u32, u32)], index: usize, predicate: F) -> Option<(u32, u32)> {
+	(0x0..spots.len())
+		.flat_map(|distance| [index.checked_sub(distance), index.checked_add(distance)])
+		.flatten()
+		.filter_map(|index| spots.get(index))
+		.find(|&spot| predicate(spot))
+		.copied()
 }
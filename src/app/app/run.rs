@@ -1,117 +1,159 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
-use crate::app::App;
-use crate::error::{Error, Result};
-use crate::log::log;
-
-use std::env::home_dir;
-use std::fs::{create_dir_all, write};
-use std::path::PathBuf;
+use crate::app::{App, Args, Command, Dirs, UserEvent};
+use crate::error::Result;
+use crate::log::{Verbosity, log};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::env::args;
+use std::num::NonZero;
+use std::thread::available_parallelism;
 use std::time::Instant;
-use winit::event_loop::{ControlFlow, EventLoop};
+use winit::event_loop::{ControlFlow, EventLoop, EventLoopProxy};
 
 impl App {
 	pub(super) fn run() -> Result<()> {
-		Self::print_welcome_message();
+		let args = Args::parse(args())?;
 
-		log!(debug, "creating event loop");
+		args.verbosity.set();
 
-		let event_loop = match EventLoop::with_user_event().build() {
-			Ok(event_loop) => event_loop,
+		log!(note, "arguments are: {args:?}");
 
-			Err(e) => panic!("unable to create event loop: {e}"),
-		};
+		match args.command.clone() {
+			Command::Play => { }
 
-		event_loop.set_control_flow(ControlFlow::Poll);
+			Command::Help => {
+				print!("{}", Args::USAGE);
 
-		let event_loop_proxy = event_loop.create_proxy();
+				return Ok(());
+			}
 
-		let mut this = Self {
-			event_loop_proxy,
+			Command::ValidateLevel(name) => {
+				let this = Self::new(args, None)?;
 
-			graphics_context: Default::default(),
+				return this.validate_level(&name);
+			}
 
-			keyboard_modifiers: Default::default(),
+			Command::ListLevels => {
+				let this = Self::new(args, None)?;
 
-			data_dir: Self::get_data_dir()?,
-			config:   Default::default(),
-			preset:   Default::default(),
-			level:    Default::default(),
+				return this.list_levels();
+			}
 
-			map:     Default::default(),
-			players: Default::default(),
+			Command::DumpLevel(name) => {
+				let this = Self::new(args, None)?;
 
-			raw_view_scale: Default::default(),
+				return this.dump_level(&name);
+			}
 
-			view_pan:   Default::default(),
-			view_scale: Self::MIN_VIEW_SCALE,
+			Command::CheckLevels => {
+				return Self::check_levels();
+			}
 
-			next_tick: Instant::now(),
+			Command::Render(path) => {
+				let mut this = Self::new(args, None)?;
+
+				this.init()?;
+
+				return this.run_render(&path);
+			}
+		}
+
+		if args.is_headless {
+			let mut this = Self::new(args, None)?;
 
-			is_paused: Default::default()
+			this.init()?;
+
+			if this.args.verbosity >= Verbosity::Normal {
+				this.print_welcome_message();
+			}
+
+			return this.run_headless();
+		}
+
+		log!(debug, "creating event loop");
+
+		let event_loop = match EventLoop::with_user_event().build() {
+			Ok(event_loop) => event_loop,
+
+			Err(e) => panic!("unable to create event loop: {e}"),
 		};
 
+		event_loop.set_control_flow(ControlFlow::Poll);
+
+		let event_loop_proxy = event_loop.create_proxy();
+
+		let mut this = Self::new(args, Some(event_loop_proxy))?;
+
 		this.init()?;
 
+		if this.args.verbosity >= Verbosity::Normal {
+			this.print_welcome_message();
+		}
+
 		event_loop.run_app(&mut this).unwrap();
 
 		Ok(())
 	}
 
-	fn get_data_dir() -> Result<PathBuf> {
-		let mut data_dir = home_dir().ok_or(Error::MissingDataDir)?;
+	fn new(args: Args, event_loop_proxy: Option<EventLoopProxy<UserEvent>>) -> Result<Self> {
+		let dirs = Dirs::find(args.data_dir.as_deref())?;
 
-		#[cfg(target_family = "unix")]
-		let data_dir = {
-			data_dir.push(".local");
-			data_dir.push("share");
-			data_dir.push("bedrock");
+		log!("using config directory \"{}\"", dirs.config.display());
+		log!("using data directory \"{}\"", dirs.data.display());
+		log!("using cache directory \"{}\"", dirs.cache.display());
 
-			data_dir
-		};
+		dirs.create()?;
 
-		#[cfg(target_family = "windows")]
-		let data_dir = {
-			data_dir.push("AppData");
-			data_dir.push("Roaming");
-			data_dir.push("Bedrock");
+		let this = Self {
+			event_loop_proxy,
 
-			data_dir
-		};
+			graphics_context: Default::default(),
 
-		log!("creating data directory at \"{}\"", data_dir.display());
+			keyboard_modifiers: Default::default(),
 
-		create_dir_all(&data_dir)
-			.map_err(|_| Error::MissingDataDir)?;
+			args,
+			dirs,
+			config:   Default::default(),
+			preset:   Default::default(),
+			level:    Default::default(),
+			rules:    Default::default(),
 
-		let subdirs = [
-			"level",
-		];
+			map:             Default::default(),
+			sticky_clusters: Default::default(),
+			players:         Default::default(),
+			projectiles:     Default::default(),
+			ballistics:      Default::default(),
 
-		for subdir in subdirs {
-			let subdir = data_dir.join(subdir);
+			rng:          ChaCha8Rng::seed_from_u64(Default::default()),
+			thread_count: available_parallelism().map_or(0x1, NonZero::get),
 
-			log!(debug, "creating subdirectory at \"{}\"", subdir.display());
+			raw_view_scale: Default::default(),
 
-			create_dir_all(&subdir)
-				.map_err(|_| Error::MissingDataDir)?;
-		}
+			view_pan:   Default::default(),
+			view_scale: Self::MIN_VIEW_SCALE,
+
+			next_tick: Instant::now(),
 
-		let test_level_path = {
-			let mut path = data_dir.clone();
+			is_paused: Default::default(),
 
-			path.push("level");
-			path.push("test");
-			path.set_extension("toml");
+			game_state: Default::default(),
 
-			path
-		};
+			round:         0x0,
+			round_sides:   0x0,
+			round_winners: Vec::new(),
 
-		log!(debug, "writing test level to \"{}\"", test_level_path.display());
+			is_config_changed: false,
+
+			level_id: None,
+
+			level_modified:  None,
+			next_level_poll: Instant::now(),
+		};
 
-		// We do not care whether this succeeds or not.
-		let _ = write(test_level_path, include_str!("test_level.toml"));
+		this.install_levels();
 
-		Ok(data_dir)
+		Ok(this)
 	}
 }
@@ -0,0 +1,77 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::{App, Args};
+use crate::error::Result;
+use crate::level::Material;
+use crate::log::log;
+
+use std::time::Instant;
+
+impl App {
+	pub(super) fn run_headless(&mut self) -> Result<()> {
+		let ticks = self.args.ticks.unwrap_or(Args::DEFAULT_TICKS);
+
+		self.tick_headless(ticks);
+
+		self.print_map_summary(ticks);
+
+		if let Some(ref path) = self.args.save_path {
+			self.save_snapshot(path)?;
+		}
+
+		if let Some(ref path) = self.args.export_path {
+			self.export_image(path)?;
+		}
+
+		Ok(())
+	}
+
+	/// Runs the given amount of ticks without waiting.
+	pub(super) fn tick_headless(&mut self, ticks: u64) {
+		log!("running `{ticks}` tick(s) headlessly");
+
+		let start = Instant::now();
+
+		for _ in 0x0..ticks {
+			self.tick();
+		}
+
+		log!(debug, "ticking took `{:?}`", start.elapsed());
+	}
+
+	fn print_map_summary(&self, ticks: u64) {
+		let (width, height) = self.map.size().get();
+
+		// Count the occurrences of each material and hash
+		// the raw blocks, so that runs can be compared.
+
+		let mut counts = [0x0u32; 0x40];
+		let mut hash   = 0xCBF29CE484222325u64;
+
+		for block in self.map.as_slice() {
+			counts[block.material() as usize] += 0x1;
+
+			hash ^= u64::from(block.to_u8());
+			hash  = hash.wrapping_mul(0x00000100000001B3);
+		}
+
+		println!("level:    {}", self.level.name);
+		println!("seed:     {}", self.preset.seed);
+		println!("size:     {width}*{height}");
+		println!("ticks:    {ticks}");
+		println!("checksum: {hash:016X}");
+		println!("materials:");
+
+		for (material, &count) in counts.iter().enumerate() {
+			if count == 0x0 {
+				continue;
+			}
+
+			// SAFETY: Only values from existing materials
+			// can have been counted.
+			let material = unsafe { Material::new_unchecked(material as u8) };
+
+			println!("  {material:?}: {count}");
+		}
+	}
+}
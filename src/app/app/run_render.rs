@@ -0,0 +1,19 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+use crate::error::Result;
+
+use std::path::Path;
+
+impl App {
+	pub(super) fn run_render(&mut self, path: &Path) -> Result<()> {
+		// Render the freshly-generated map unless asked
+		// to simulate it first.
+
+		let ticks = self.args.ticks.unwrap_or_default();
+
+		self.tick_headless(ticks);
+
+		self.export_image(path)
+	}
+}
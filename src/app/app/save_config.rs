@@ -0,0 +1,129 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::{App, Config};
+use crate::app::app::load_config::{
+	ConfigGameHelper,
+	ConfigHelper,
+	ConfigNetworkHelper,
+	ConfigPlayerHelper,
+	ConfigWindowHelper,
+	parse_config,
+};
+use crate::log::log;
+
+use std::fs::{read_to_string, write};
+use toml_edit::{DocumentMut, Item};
+
+impl App {
+	/// Writes changes to the configuration back to the config directory.
+	///
+	/// Only the keys whose values differ from those in the file are rewritten, so that comments and formatting are kept.
+	/// Failures are logged but otherwise ignored.
+	pub(super) fn save_config(&self) {
+		let path = self.dirs.config_path();
+
+		log!("saving config to \"{}\"", path.display());
+
+		let source = read_to_string(&path).unwrap_or_else(|_| Config::DEFAULT_FILE.into());
+
+		let saved = match parse_config(&source, &path) {
+			Ok(saved) => saved,
+
+			Err(e) => {
+				log!(warning, "not saving config over invalid file: {e}");
+				return;
+			}
+		};
+
+		let mut document = match source.parse::<DocumentMut>() {
+			Ok(document) => document,
+
+			Err(e) => {
+				log!(warning, "unable to edit config: {e}");
+				return;
+			}
+		};
+
+		let (Ok(old), Ok(new)) = (to_table(&saved), to_table(&self.config)) else {
+			log!(warning, "unable to serialise config");
+			return;
+		};
+
+		for (section, table) in &new {
+			let Some(table) = table.as_table() else { continue };
+
+			let old_table = old.get(section).and_then(toml::Value::as_table);
+
+			for (key, value) in table {
+				if old_table.and_then(|old_table| old_table.get(key)) == Some(value) {
+					continue;
+				}
+
+				// Values are printed in their TOML form, and
+				// can therefore always be parsed back.
+
+				let value = value
+					.to_string()
+					.parse::<toml_edit::Value>()
+					.expect("serialised value should be valid");
+
+				document[section.as_str()][key.as_str()] = Item::Value(value);
+			}
+		}
+
+		// Remove keys that are no longer set, e.g. unset
+		// addresses.
+
+		for (section, old_table) in &old {
+			let Some(old_table) = old_table.as_table() else { continue };
+
+			let new_table = new.get(section).and_then(toml::Value::as_table);
+
+			let Some(table) = document.get_mut(section).and_then(Item::as_table_like_mut) else { continue };
+
+			for key in old_table.keys() {
+				if new_table.is_none_or(|new_table| !new_table.contains_key(key)) {
+					let _ = table.remove(key);
+				}
+			}
+		}
+
+		if let Err(e) = write(&path, document.to_string()) {
+			log!(warning, "unable to save config: {e}");
+		}
+	}
+}
+
+/// Serialises a configuration as a table of sections.
+fn to_table(config: &Config) -> Result<toml::Table, toml::ser::Error> {
+	let helper = ConfigHelper {
+		player: ConfigPlayerHelper {
+			name:  Some(config.player_name.to_string()),
+			token: Some(config.token),
+			team:  Some(config.team),
+		},
+
+		game: ConfigGameHelper {
+			level: config.level.as_deref().map(Into::into),
+		},
+
+		window: ConfigWindowHelper {
+			size:       Some(config.window.size),
+			fullscreen: Some(config.window.is_fullscreen),
+		},
+
+		keys: config.keys,
+
+		network: ConfigNetworkHelper {
+			addr:     config.addr,
+			lobby:    Some(config.lobby.to_string()),
+			password: Some(config.password.to_string()),
+		},
+	};
+
+	let toml::Value::Table(table) = toml::Value::try_from(helper)? else {
+		unreachable!("config should serialise as a table");
+	};
+
+	Ok(table)
+}
@@ -0,0 +1,29 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+use crate::error::{Error, Result};
+use crate::log::log;
+use crate::snapshot::Snapshot;
+
+use std::fs::write;
+use std::path::Path;
+
+impl App {
+	pub(super) fn save_snapshot(&self, path: &Path) -> Result<()> {
+		log!("saving snapshot to \"{}\"", path.display());
+
+		let snapshot = Snapshot {
+			preset: self.preset.clone(),
+			level:  self.level.clone(),
+			map:    self.map.clone(),
+			rng:    self.rng.clone(),
+		};
+
+		let data = snapshot.encode();
+
+		write(path, data)
+			.map_err(|e| Error::UnwritableSnapshot { path: path.into(), source: Box::new(e) })?;
+
+		Ok(())
+	}
+}
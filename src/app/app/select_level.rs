@@ -0,0 +1,94 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::{App, UserEvent};
+use crate::level::LevelInfo;
+use crate::log::log;
+
+use std::mem::replace;
+
+impl App {
+	/// Switches to another of the available levels.
+	///
+	/// The offset is relative to the loaded level in the order of [`LevelInfo::discover`].
+	pub(super) fn select_level(&mut self, offset: isize) {
+		let levels = LevelInfo::discover(&self.dirs.level_dir());
+
+		if levels.is_empty() {
+			log!(warning, "there are no levels to select");
+			return;
+		}
+
+		let index = self
+			.level_id
+			.as_ref()
+			.and_then(|id| levels.iter().position(|level| level.id == *id));
+
+		let index = match index {
+			// Wrap around at either end.
+
+			Some(index) => index
+				.checked_add_signed(offset)
+				.map_or(levels.len() - 0x1, |index| index % levels.len()),
+
+			None => 0x0,
+		};
+
+		let id = levels[index].id.clone();
+
+		self.switch_level(id);
+	}
+
+	/// Loads and generates the given level.
+	///
+	/// Failures are logged and leave the current level in place.
+	/// Otherwise, any match in progress is abandoned, as its rounds would not be played on the same level.
+	pub(super) fn switch_level(&mut self, id: Box<str>) {
+		if !self.replace_level(id) {
+			return;
+		}
+
+		self.reset_game();
+
+		self.regenerate_level();
+
+		if let Err(e) = self.respawn_players() {
+			log!(error, "{e}");
+		}
+
+		self.create_user_event(UserEvent::RedrawMap);
+	}
+
+	/// Loads the given level and its rules in place of the current ones.
+	///
+	/// The map is not regenerated.
+	/// Failures are logged and leave the current level in place, in which case `false` is returned.
+	pub(super) fn replace_level(&mut self, id: Box<str>) -> bool {
+		let level = match self.load_level(&id) {
+			Ok(level) => level,
+
+			Err(e) => {
+				log!(error, "{e}");
+				return false;
+			}
+		};
+
+		let previous = replace(&mut self.level, level);
+
+		match self.load_rules() {
+			Ok(rules) => self.rules = rules,
+
+			Err(e) => {
+				log!(error, "{e}");
+
+				self.level = previous;
+				return false;
+			}
+		}
+
+		self.level_id = Some(id);
+
+		self.level_modified = self.level_file_modified();
+
+		true
+	}
+}
@@ -1,125 +1,160 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
 use crate::app::App;
-use crate::level::Material;
+use crate::map::{BandMut, BandParity, ColumnWindowsMut};
+use crate::rule::{Axis, RuleSet};
 
-use rand::random;
-use std::mem::swap;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::thread;
 
-const fn min_seed(chance_num: u32, chance_den: u32) -> u32 {
-	assert!(chance_den != 0x0);
-	assert!(chance_den >= chance_num);
+impl App {
+	/// The width of the bands that are ticked in parallel.
+	///
+	/// Note that this must not depend on the amount of threads, as the results of ticks would otherwise differ between machines.
+	const BAND_WIDTH: u32 = 0x20;
+
+	pub(super) fn tick(&mut self) {
+		if self.is_paused {
+			return;
+		}
 
-	let chance_num: u32 = chance_num;
-	let chance_den: u32 = chance_den;
+		// Vertical rules only affect single columns, so
+		// all bands can be ticked at once. Horizontal
+		// rules reach into the following band, so we
+		// first tick the even bands and then the odd.
 
-	let num = u32::MAX as u64 * chance_num as u64;
-	let den = chance_den as u64;
+		let bands = self.map.bands_mut(Self::BAND_WIDTH);
+		tick_bands(bands, Axis::Vertical, &self.rules, &mut self.rng, self.thread_count);
+
+		for parity in [BandParity::Even, BandParity::Odd] {
+			let bands = self.map.interleaved_bands_mut(Self::BAND_WIDTH, parity);
+			tick_bands(bands, Axis::Horizontal, &self.rules, &mut self.rng, self.thread_count);
+		}
 
-	u32::MAX - (num / den) as u32
+		self.tick_projectiles();
+		self.tick_players();
+
+		// Sticky clusters must see every change of the
+		// tick, including those of explosions.
+
+		self.sticky_clusters.update(&mut self.map);
+
+		self.map.settle();
+
+		self.tick_game();
+	}
 }
 
-macro_rules! select_events {
-	{
-		Meta {
-			seed: $seed:expr$(,)?
-		}$(,)?
-
-		$(
-			Event {
-				lhs:    $lhs_expr:expr,
-				rhs:    $rhs_expr:expr,
-				chance: $chance_num:literal / $chance_den:literal$(,)?
-			} => $op:block$(,)?
-		)*
-	} => {{
-		let seed: u32 = $seed;
-
-		$({
-			let min_seed = ::bedrock::app::app::tick::min_seed($chance_num, $chance_den);
-
-			if seed >= min_seed && $lhs_expr && $rhs_expr {
-				$op;
-			}
-		})*
-	}};
+fn tick_bands(
+	bands:        Vec<BandMut>,
+	axis:         Axis,
+	rules:        &RuleSet,
+	rng:          &mut ChaCha8Rng,
+	thread_count: usize,
+) {
+	// Seed the bands in order, so that the outcome
+	// does not depend on how they are scheduled.
+
+	let mut bands: Vec<_> = bands
+		.into_iter()
+		.map(|band| (band, ChaCha8Rng::seed_from_u64(rng.random())))
+		.collect();
+
+	let group_len = bands.len().div_ceil(thread_count).max(0x1);
+
+	thread::scope(|scope| {
+		let mut groups = bands.chunks_mut(group_len);
+
+		let first_group = groups.next();
+
+		for group in groups {
+			scope.spawn(move || tick_group(group, axis, rules));
+		}
+
+		// Use the current thread as well.
+
+		if let Some(group) = first_group {
+			tick_group(group, axis, rules);
+		}
+	});
 }
 
-impl App {
-	pub(super) fn tick(&mut self) {
-		if self.is_paused {
-			return;
+fn tick_group(group: &mut [(BandMut, ChaCha8Rng)], axis: Axis, rules: &RuleSet) {
+	for &mut (ref mut band, ref mut rng) in group {
+		match axis {
+			Axis::Vertical => tick_columns(band, rules, rng),
+
+			Axis::Horizontal => tick_rows(band, rules, rng),
+
+			Axis::Any => unreachable!(),
 		}
+	}
+}
+
+fn tick_columns(band: &mut BandMut, rules: &RuleSet, rng: &mut ChaCha8Rng) {
+	let (columns, states) = band.columns_mut();
+
+	for (column, state) in columns.zip(states) {
+		// Skip settled columns.
+
+		if !state.is_active() {
+			continue;
+		}
+
+		let mut windows = ColumnWindowsMut::new(column);
+
+		let mut is_changed = false;
 
-		let mut columns = self.map.columns_mut();
-
-		while let Some(mut windows) = columns.next_as_windows_mut() {
-			while let Some([block, next_block]) = windows.next() {
-				select_events! {
-					Meta {
-						seed: random(),
-					}
-
-					Event {
-						lhs:    block.is_emtpy(),
-						rhs:    !next_block.is_static() && !next_block.is_liquid(),
-						chance: 0x1F / 0x20,
-					} => {
-						swap(block, next_block);
-					}
-
-					Event {
-						lhs:    block.is_liquid(),
-						rhs:    !next_block.is_static() && !next_block.is_liquid(),
-						chance: 0x1 / 0x2,
-					} => {
-						swap(block, next_block);
-					}
-
-					Event {
-						lhs:    block.is_liquid(),
-						rhs:    !next_block.is_static() && next_block.is_liquid(),
-						chance: 0x1 / 0x8,
-					} => {
-						swap(block, next_block);
-
-						// Skip the next block.
-						let _ = windows.next();
-					}
-
-					Event {
-						lhs:    block.material() == Material::Dirt,
-						rhs:    next_block.is_emtpy(),
-						chance: 0x1 / 0x40,
-					} => {
-						block.set_material(Material::Grass);
-					}
-
-					Event {
-						lhs:    block.material() == Material::Dirt,
-						rhs:    next_block.material() == Material::Grass,
-						chance: 0x1 / 0x80,
-					} => {
-						block.set_material(Material::Grass);
-					}
-
-					Event {
-						lhs:    block.material() == Material::Grass,
-						rhs:    !next_block.is_emtpy(),
-						chance: 0x1 / 0x80,
-					} => {
-						block.set_material(Material::Dirt);
-					}
-
-					Event {
-						lhs:    block.material() == Material::Fire,
-						rhs:    true,
-						chance: 0x1 / 0x20,
-					} => {
-						block.set_material(Material::Air);
-					}
-				}
+		while let Some([block, next_block]) = windows.next() {
+			let seed = rng.random();
+
+			let old = [*block, *next_block];
+
+			let skip = rules.apply(Axis::Vertical, block, next_block, seed);
+
+			is_changed |= [*block, *next_block] != old;
+
+			if skip {
+				let _ = windows.next();
 			}
 		}
+
+		if is_changed {
+			state.mark_changed();
+		}
+	}
+}
+
+fn tick_rows(band: &mut BandMut, rules: &RuleSet, rng: &mut ChaCha8Rng) {
+	let (mut windows, states) = band.row_windows_mut();
+
+	loop {
+		let x = windows.x() as usize;
+
+		let Some([block, next_block]) = windows.next() else {
+			break;
+		};
+
+		// Skip windows where both columns are settled.
+
+		if !states[x].is_active() && !states[x + 0x1].is_active() {
+			continue;
+		}
+
+		let seed = rng.random();
+
+		let old = [*block, *next_block];
+
+		let skip = rules.apply(Axis::Horizontal, block, next_block, seed);
+
+		if [*block, *next_block] != old {
+			states[x].mark_changed();
+			states[x + 0x1].mark_changed();
+		}
+
+		if skip {
+			windows.skip();
+		}
 	}
 }
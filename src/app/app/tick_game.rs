@@ -0,0 +1,353 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::{App, GameState, Side, UserEvent};
+use crate::log::log;
+use crate::player::{Player, Walk};
+
+impl App {
+	/// The length of the countdown before each round, in seconds.
+	pub const COUNTDOWN_TIME: u16 = 0x3;
+
+	/// The time that the outcome of a round is shown, in seconds.
+	pub const ROUND_OVER_TIME: u16 = 0x3;
+
+	/// The longest time that is waited for the simulation to settle after a turn, in seconds.
+	///
+	/// Some reactions -- e.g. flowing liquids or spreading fires -- may never settle, so the next turn is started regardless.
+	pub const MAX_SETTLE_TIME: u16 = 0xA;
+
+	/// Starts a new match.
+	///
+	/// This is the local counterpart to [`Message::Start`](crate::message::Message::Start).
+	pub(super) fn start_game(&mut self) {
+		if self.game_state.is_playing() {
+			log!(warning, "game has already been started");
+			return;
+		}
+
+		if self.players.is_empty() {
+			log!(warning, "unable to start game without players");
+			return;
+		}
+
+		log!("starting game with `{}` round(s)", self.preset.round_count);
+
+		self.round = 0x0;
+		self.round_winners.clear();
+
+		self.begin_round();
+	}
+
+	/// Returns to the lobby, discarding the current match, if any.
+	pub(super) fn reset_game(&mut self) {
+		if self.game_state.is_playing() {
+			log!(warning, "abandoning game in round `{}`", self.round);
+		}
+
+		self.round       = 0x0;
+		self.round_sides = 0x0;
+		self.round_winners.clear();
+
+		self.game_state = GameState::Lobby;
+	}
+
+	/// Ends the current turn.
+	///
+	/// The active player stops walking, and the simulation is allowed to settle before the next turn.
+	pub(super) fn end_turn(&mut self) {
+		let GameState::Turn { player: index, .. } = self.game_state else {
+			log!(warning, "no turn to end");
+			return;
+		};
+
+		if let Some(player) = self.players.iter_mut().nth(index) {
+			log!("turn of player \"{}\" has ended", player.name);
+
+			player.walk = Walk::Still;
+		}
+
+		self.game_state = GameState::Settling {
+			previous: index,
+			ticks:    self.seconds_to_ticks(Self::MAX_SETTLE_TIME),
+		};
+	}
+
+	/// Advances the game state by a single tick.
+	pub(super) fn tick_game(&mut self) {
+		match self.game_state {
+			GameState::Lobby | GameState::Results => { }
+
+			GameState::Countdown { ticks } => {
+				if ticks != 0x0 {
+					self.game_state = GameState::Countdown { ticks: ticks - 0x1 };
+					return;
+				}
+
+				self.begin_turn(self.first_player());
+			}
+
+			GameState::Turn { player, ticks } => {
+				let is_alive = self
+					.players
+					.iter()
+					.nth(player)
+					.is_some_and(Player::is_alive);
+
+				if !is_alive {
+					log!("active player has died");
+
+					self.end_turn();
+					return;
+				}
+
+				if ticks == 0x0 {
+					log!("time is up");
+
+					self.end_turn();
+					return;
+				}
+
+				self.game_state = GameState::Turn { player, ticks: ticks - 0x1 };
+			}
+
+			GameState::Settling { previous, ticks } => {
+				if ticks != 0x0 && !self.is_calm() {
+					self.game_state = GameState::Settling { previous, ticks: ticks - 0x1 };
+					return;
+				}
+
+				if ticks == 0x0 {
+					log!(note, "simulation did not settle in time");
+				}
+
+				if self.is_round_decided() {
+					self.end_round();
+				} else {
+					self.begin_turn(self.next_player(previous));
+				}
+			}
+
+			GameState::RoundOver { ticks } => {
+				if ticks != 0x0 {
+					self.game_state = GameState::RoundOver { ticks: ticks - 0x1 };
+					return;
+				}
+
+				if self.round >= self.preset.round_count {
+					self.show_results();
+				} else {
+					self.begin_round();
+				}
+			}
+		}
+	}
+
+	/// Regenerates the level with the seed of the next round and respawns all players.
+	fn begin_round(&mut self) {
+		self.round += 0x1;
+
+		log!("beginning round `{}` of `{}`", self.round, self.preset.round_count);
+
+		self.projectiles.clear();
+
+		self.regenerate_level();
+
+		if let Err(e) = self.respawn_players() {
+			log!(error, "{e}");
+
+			self.game_state = GameState::Lobby;
+			return;
+		}
+
+		self.round_sides = self.living_sides().len();
+
+		self.game_state = GameState::Countdown {
+			ticks: self.seconds_to_ticks(Self::COUNTDOWN_TIME),
+		};
+
+		self.create_user_event(UserEvent::RedrawMap);
+	}
+
+	/// Gives the turn to the given player.
+	///
+	/// If there is no player to take the turn, then the round is ended instead.
+	fn begin_turn(&mut self, index: Option<usize>) {
+		let Some(index) = index else {
+			self.end_round();
+			return;
+		};
+
+		if let Some(player) = self.players.iter().nth(index) {
+			log!("it is now the turn of player \"{}\"", player.name);
+		}
+
+		self.game_state = GameState::Turn {
+			player: index,
+			ticks:  self.seconds_to_ticks(self.preset.turn_time),
+		};
+	}
+
+	/// Records the winner of the round.
+	fn end_round(&mut self) {
+		let sides = self.living_sides();
+
+		let winner = match *sides.as_slice() {
+			[side] => Some(side),
+
+			_ => None,
+		};
+
+		match winner {
+			Some(side) => log!("round `{}` was won by {}", self.round, self.side_name(side)),
+
+			None => log!("round `{}` was drawn", self.round),
+		}
+
+		self.round_winners.push(winner);
+
+		self.game_state = GameState::RoundOver {
+			ticks: self.seconds_to_ticks(Self::ROUND_OVER_TIME),
+		};
+	}
+
+	/// Tallies the rounds won by each side.
+	fn show_results(&mut self) {
+		log!("game has ended");
+
+		let mut tally: Vec<(Side, u8)> = Vec::new();
+		let mut draws = 0x0u8;
+
+		for &winner in &self.round_winners {
+			let Some(winner) = winner else {
+				draws += 0x1;
+				continue;
+			};
+
+			match tally.iter_mut().find(|&&mut (side, _)| side == winner) {
+				Some(&mut (_, ref mut wins)) => *wins += 0x1,
+
+				None => tally.push((winner, 0x1)),
+			}
+		}
+
+		tally.sort_by(|&(_, lhs), &(_, rhs)| rhs.cmp(&lhs));
+
+		for (side, wins) in tally {
+			log!("{} won `{wins}` round(s)", self.side_name(side));
+		}
+
+		if draws != 0x0 {
+			log!("`{draws}` round(s) were drawn");
+		}
+
+		self.game_state = GameState::Results;
+	}
+
+	/// Chooses the player who takes the first turn of the round.
+	///
+	/// The side that starts is rotated every round, so that no side always gets the first shot.
+	/// The first living player of that side is chosen.
+	#[must_use]
+	fn first_player(&self) -> Option<usize> {
+		let sides = self.living_sides();
+
+		let round = usize::from(self.round.saturating_sub(0x1));
+
+		let side = *sides.get(round % sides.len().max(0x1))?;
+
+		self
+			.players
+			.iter()
+			.enumerate()
+			.filter(|&(_, player)| player.is_spawned && player.is_alive())
+			.find(|&(index, player)| Side::of(index, player) == side)
+			.map(|(index, _)| index)
+	}
+
+	/// Chooses the player whose turn is next.
+	///
+	/// Turns alternate between sides: The next living player after the previous one that is on a different side is chosen.
+	/// If all living players are on the same side, then the next living player is chosen instead.
+	#[must_use]
+	fn next_player(&self, previous: usize) -> Option<usize> {
+		let players: Vec<&Player> = self.players.iter().collect();
+
+		let start = previous + 0x1;
+
+		let previous_side = players
+			.get(previous)
+			.map(|player| Side::of(previous, player));
+
+		let mut candidates = (0x0..players.len())
+			.map(|offset| (start + offset) % players.len())
+			.filter(|&index| players[index].is_spawned && players[index].is_alive());
+
+		candidates
+			.clone()
+			.find(|&index| Some(Side::of(index, players[index])) != previous_side)
+			.or_else(|| candidates.next())
+	}
+
+	/// Gets the sides that still have living players.
+	#[must_use]
+	fn living_sides(&self) -> Vec<Side> {
+		let mut sides = Vec::new();
+
+		for (index, player) in self.players.iter().enumerate() {
+			if !player.is_spawned || !player.is_alive() {
+				continue;
+			}
+
+			let side = Side::of(index, player);
+
+			if !sides.contains(&side) {
+				sides.push(side);
+			}
+		}
+
+		sides
+	}
+
+	/// Tests if the round has been decided.
+	///
+	/// This is the case when at most one side is left standing.
+	/// Rounds with a single side -- e.g. for practice -- are instead decided once that side has fallen.
+	#[must_use]
+	fn is_round_decided(&self) -> bool {
+		self.living_sides().len() < self.round_sides.min(0x2)
+	}
+
+	/// Tests if the simulation has calmed down.
+	///
+	/// This requires the map to be settled, all projectiles to have landed, and all players to be standing.
+	#[must_use]
+	fn is_calm(&self) -> bool {
+		self.map.is_settled()
+			&& self.projectiles.is_empty()
+			&& self
+				.players
+				.iter()
+				.filter(|player| player.is_spawned && player.is_alive())
+				.all(|player| player.fall_start.is_none())
+	}
+
+	/// Gets the display name of a side.
+	#[must_use]
+	fn side_name(&self, side: Side) -> String {
+		match side {
+			Side::Team(team) => format!("team {team:?}").to_lowercase(),
+
+			Side::Solo(index) => self
+				.players
+				.iter()
+				.nth(index)
+				.map_or_else(|| format!("player `{index}`"), |player| format!("\"{}\"", player.name)),
+		}
+	}
+
+	#[inline]
+	#[must_use]
+	fn seconds_to_ticks(&self, seconds: u16) -> u32 {
+		u32::from(seconds) * u32::from(self.preset.tps)
+	}
+}
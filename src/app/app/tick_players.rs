@@ -0,0 +1,23 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+use crate::log::log;
+
+impl App {
+	/// Steps all players by a single tick.
+	pub(super) fn tick_players(&mut self) {
+		for player in self.players.iter_mut() {
+			let was_alive = player.is_alive();
+
+			let damage = player.step(&self.map);
+
+			if damage != 0x0 {
+				log!(debug, "player \"{}\" took `{damage}` damage", player.name);
+			}
+
+			if was_alive && !player.is_alive() {
+				log!("player \"{}\" died", player.name);
+			}
+		}
+	}
+}
@@ -0,0 +1,48 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+use crate::log::log;
+use crate::map::Explosion;
+use crate::projectile::Flight;
+
+impl App {
+	/// Steps all projectiles by a single tick.
+	///
+	/// Projectiles that collide explode -- damaging nearby players -- and those that leave the map are removed.
+	pub(super) fn tick_projectiles(&mut self) {
+		let map        = &self.map;
+		let ballistics = &self.ballistics;
+
+		let mut impacts = Vec::new();
+
+		self.projectiles.retain_mut(|projectile| {
+			match projectile.step(map, ballistics) {
+				Flight::Airborne => true,
+
+				Flight::Collided { x, y } => {
+					log!(debug, "projectile collided at `({x}, {y})`");
+
+					impacts.push((f64::from(x) + 0.5, f64::from(y) + 0.5));
+
+					false
+				}
+
+				Flight::Escaped => {
+					log!(debug, "projectile left the map");
+
+					false
+				}
+			}
+		});
+
+		for centre in impacts {
+			let explosion = Explosion {
+				centre,
+
+				..Explosion::SHELL
+			};
+
+			self.explode(&explosion);
+		}
+	}
+}
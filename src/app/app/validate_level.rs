@@ -0,0 +1,16 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::App;
+use crate::error::Result;
+
+impl App {
+	pub(super) fn validate_level(&self, name: &str) -> Result<()> {
+		// Levels are always validated when loaded.
+
+		let level = self.load_level(name)?;
+
+		println!("level \"{name}\" ({}) is valid", level.name);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,287 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::Command;
+use crate::error::{Error, Result};
+use crate::graphics::MapImage;
+use crate::log::Verbosity;
+use crate::map::MapSize;
+
+use std::num::NonZero;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Parsed command line arguments.
+#[derive(Clone, Debug, Default)]
+pub struct Args {
+	/// The subcommand to run.
+	pub command: Command,
+
+	/// The level to load, if not the default.
+	pub level: Option<Box<str>>,
+
+	/// The seed to use, if not a random one.
+	pub seed: Option<u64>,
+
+	/// The map size to use, if not the preset's.
+	pub map_size: Option<MapSize>,
+
+	/// The tick rate to use, if not the preset's.
+	pub tps: Option<u16>,
+
+	/// The directory to keep all files in, if not the default.
+	pub data_dir: Option<Box<Path>>,
+
+	/// The inner window size, if not the default.
+	pub window_size: Option<(u32, u32)>,
+
+	/// Denotes whether to open the window in fullscreen.
+	pub is_fullscreen: bool,
+
+	/// The log verbosity.
+	pub verbosity: Verbosity,
+
+	/// The snapshot to load instead of generating a map.
+	pub snapshot: Option<Box<Path>>,
+
+	/// The path to save a snapshot to after running headlessly.
+	pub save_path: Option<Box<Path>>,
+
+	/// The path to export a map image to after running headlessly.
+	pub export_path: Option<Box<Path>>,
+
+	/// The scale of exported map images.
+	pub export_scale: u32,
+
+	/// Denotes whether to run without a window.
+	pub is_headless: bool,
+
+	/// The amount of ticks to simulate whilst headless, if not the default.
+	pub ticks: Option<u64>,
+}
+
+impl Args {
+	/// The default amount of headless ticks.
+	pub const DEFAULT_TICKS: u64 = 0x400;
+
+	/// The usage information.
+	pub const USAGE: &'static str = include_str!("usage.txt");
+
+	/// Parses arguments from the given iterator.
+	///
+	/// The first item is expected to be the programme name and is therefore skipped.
+	pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self> {
+		let mut this = Self {
+			export_scale: 0x1,
+
+			..Default::default()
+		};
+
+		let mut positionals = Vec::new();
+
+		let mut args = args.into_iter().skip(0x1);
+
+		while let Some(arg) = args.next() {
+			match &*arg {
+				"--data-dir" => {
+					let value = next_value(&mut args, &arg)?;
+
+					this.data_dir = Some(Path::new(&value).into());
+				}
+
+				"--export" => {
+					let value = next_value(&mut args, &arg)?;
+
+					this.export_path = Some(Path::new(&value).into());
+				}
+
+				"--export-scale" => {
+					let value = next_value(&mut args, &arg)?;
+
+					let scale = parse_value(&value)?;
+
+					if !(0x1..=MapImage::MAX_SCALE).contains(&scale) {
+						return Err(Error::UnknownCliArg(value.into()));
+					}
+
+					this.export_scale = scale;
+				}
+
+				"--fullscreen" => {
+					this.is_fullscreen = true;
+				}
+
+				"--headless" => {
+					this.is_headless = true;
+				}
+
+				"-h" | "--help" => {
+					this.command = Command::Help;
+				}
+
+				"--level" => {
+					let value = next_value(&mut args, &arg)?;
+
+					this.level = Some(value.into());
+				}
+
+				"--load" => {
+					let value = next_value(&mut args, &arg)?;
+
+					this.snapshot = Some(Path::new(&value).into());
+				}
+
+				"--map-size" => {
+					let value = next_value(&mut args, &arg)?;
+
+					let (width, height) = parse_size(&value)?;
+
+					let map_size = MapSize::new(width, height)
+						.ok_or_else(|| Error::UnknownCliArg(value.into()))?;
+
+					this.map_size = Some(map_size);
+				}
+
+				"-q" | "--quiet" => {
+					this.verbosity = Verbosity::Quiet;
+				}
+
+				"--save" => {
+					let value = next_value(&mut args, &arg)?;
+
+					this.save_path = Some(Path::new(&value).into());
+				}
+
+				"--seed" => {
+					let value = next_value(&mut args, &arg)?;
+
+					this.seed = Some(parse_value(&value)?);
+				}
+
+				"--ticks" => {
+					let value = next_value(&mut args, &arg)?;
+
+					this.ticks = Some(parse_value(&value)?);
+				}
+
+				"--tps" => {
+					let value = next_value(&mut args, &arg)?;
+
+					let tps = parse_value(&value)?;
+
+					if tps == 0x0 {
+						return Err(Error::UnknownCliArg(value.into()));
+					}
+
+					this.tps = Some(tps);
+				}
+
+				"-v" | "--verbose" => {
+					this.verbosity = Verbosity::Verbose;
+				}
+
+				"--window-size" => {
+					let value = next_value(&mut args, &arg)?;
+
+					this.window_size = Some(parse_size(&value)?);
+				}
+
+				_ if arg.starts_with('-') => {
+					return Err(Error::UnknownCliArg(arg.into()));
+				}
+
+				_ => {
+					positionals.push(arg);
+				}
+			}
+		}
+
+		this.parse_positionals(positionals)?;
+
+		Ok(this)
+	}
+
+	fn parse_positionals(&mut self, positionals: Vec<String>) -> Result<()> {
+		let mut positionals = positionals.into_iter();
+
+		let Some(first) = positionals.next() else {
+			return Ok(());
+		};
+
+		match &*first {
+			"help" => {
+				self.command = Command::Help;
+			}
+
+			"check-levels" => {
+				self.command = Command::CheckLevels;
+			}
+
+			"dump-level" => {
+				let level = next_value(&mut positionals, &first)?;
+
+				self.command = Command::DumpLevel(level.into());
+			}
+
+			"list-levels" => {
+				self.command = Command::ListLevels;
+			}
+
+			"render" => {
+				let path = next_value(&mut positionals, &first)?;
+
+				self.command = Command::Render(Path::new(&path).into());
+			}
+
+			"validate-level" => {
+				let level = next_value(&mut positionals, &first)?;
+
+				self.command = Command::ValidateLevel(level.into());
+			}
+
+			// Accept a lone level name for compatibility
+			// with older invocations.
+
+			_ if self.level.is_none() => {
+				self.level = Some(first.into());
+			}
+
+			_ => {
+				return Err(Error::UnknownCliArg(first.into()));
+			}
+		}
+
+		if let Some(arg) = positionals.next() {
+			return Err(Error::UnknownCliArg(arg.into()));
+		}
+
+		Ok(())
+	}
+}
+
+#[inline]
+fn next_value<I: Iterator<Item = String>>(args: &mut I, flag: &str) -> Result<String> {
+	args
+		.next()
+		.ok_or_else(|| Error::UnknownCliArg(flag.into()))
+}
+
+#[inline]
+fn parse_value<T: FromStr>(value: &str) -> Result<T> {
+	value
+		.parse()
+		.map_err(|_| Error::UnknownCliArg(value.into()))
+}
+
+/// Parses a size on the form `<width>x<height>`.
+///
+/// Neither the width nor the height may be zero.
+fn parse_size(value: &str) -> Result<(u32, u32)> {
+	let (width, height) = value
+		.split_once('x')
+		.ok_or_else(|| Error::UnknownCliArg(value.into()))?;
+
+	let width:  NonZero<u32> = parse_value(width)?;
+	let height: NonZero<u32> = parse_value(height)?;
+
+	Ok((width.get(), height.get()))
+}
@@ -0,0 +1,29 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use std::path::Path;
+
+/// A command line subcommand.
+#[derive(Clone, Debug, Default)]
+pub enum Command {
+	/// Play the game, either windowed or headlessly.
+	#[default]
+	Play,
+
+	/// Print usage information.
+	Help,
+
+	/// Load and validate a level.
+	ValidateLevel(Box<str>),
+
+	/// List the available levels.
+	ListLevels,
+
+	/// Print a level as TOML.
+	DumpLevel(Box<str>),
+
+	/// Check that the built-in levels survive serialisation.
+	CheckLevels,
+
+	/// Render a map image headlessly.
+	Render(Box<Path>),
+}
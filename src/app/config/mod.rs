@@ -1,12 +1,69 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
+use crate::app::KeyBindings;
+use crate::graphics::WindowSettings;
 use crate::message::{LobbyName, LobbyPassword};
+use crate::player::{Team, Token};
 
 use std::net::SocketAddr;
 
-#[derive(Clone, Debug, Default)]
+/// The user configuration.
+///
+/// This is loaded from `config.toml` in the config directory.
+/// Command line arguments take precedence over the configuration.
+#[derive(Clone, Debug, PartialEq)]
 pub struct Config {
-	pub addr:     Option<SocketAddr>,
-	pub name:     LobbyName,
+	/// The name of the local player.
+	pub player_name: Box<str>,
+
+	/// The token of the local player.
+	pub token: Token,
+
+	/// The team of the local player.
+	pub team: Team,
+
+	/// The level to load by default.
+	pub level: Option<Box<str>>,
+
+	/// The settings of the game window.
+	pub window: WindowSettings,
+
+	/// The key bindings.
+	pub keys: KeyBindings,
+
+	/// The address of the lobby to join, if any.
+	pub addr: Option<SocketAddr>,
+
+	/// The name of the lobby to join or host.
+	pub lobby: LobbyName,
+
+	/// The password of the lobby to join or host.
 	pub password: LobbyPassword,
 }
+
+impl Config {
+	/// The default configuration file.
+	///
+	/// This is written to the config directory if no configuration exists.
+	pub const DEFAULT_FILE: &'static str = include_str!("default.toml");
+}
+
+impl Default for Config {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			player_name: "epsiloneridani".into(),
+			token:       Default::default(),
+			team:        Default::default(),
+
+			level: None,
+
+			window: Default::default(),
+			keys:   Default::default(),
+
+			addr:     None,
+			lobby:    Default::default(),
+			password: Default::default(),
+		}
+	}
+}
@@ -0,0 +1,168 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::error::{Error, Result};
+use crate::log::log;
+
+use std::env::{home_dir, var_os};
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+
+/// The directories used by the game.
+///
+/// On UNIX-compatible systems, these follow the XDG base directory specification:
+/// The configuration is kept in `$XDG_CONFIG_HOME/bedrock`, levels, rules, snapshots, and exported images in `$XDG_DATA_HOME/bedrock`, and generated state -- which may be deleted at any time -- in `$XDG_CACHE_HOME/bedrock`.
+///
+/// If a custom directory is specified -- either by command line or by the [`BEDROCK_DATA_DIR`](Self::OVERRIDE_VAR) environment variable -- then everything is kept in that directory instead.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dirs {
+	pub config: PathBuf,
+	pub data:   PathBuf,
+	pub cache:  PathBuf,
+}
+
+impl Dirs {
+	/// The environment variable that overrides all directories.
+	pub const OVERRIDE_VAR: &'static str = "BEDROCK_DATA_DIR";
+
+	/// Finds the directories to use.
+	///
+	/// The custom directory, if any, takes precedence over the environment.
+	pub fn find(custom: Option<&Path>) -> Result<Self> {
+		let custom = custom
+			.map(Path::to_path_buf)
+			.or_else(|| var_os(Self::OVERRIDE_VAR).filter(|dir| !dir.is_empty()).map(PathBuf::from));
+
+		if let Some(dir) = custom {
+			log!(note, "using custom data directory \"{}\"", dir.display());
+
+			let this = Self {
+				config: dir.clone(),
+				data:   dir.clone(),
+				cache:  dir.join("cache"),
+			};
+
+			return Ok(this);
+		}
+
+		Self::find_default()
+	}
+
+	#[cfg(target_family = "unix")]
+	fn find_default() -> Result<Self> {
+		let home = home_dir().ok_or(Error::MissingDataDir)?;
+
+		let config = xdg_dir("XDG_CONFIG_HOME", || home.join(".config"));
+		let data   = xdg_dir("XDG_DATA_HOME",   || home.join(".local").join("share"));
+		let cache  = xdg_dir("XDG_CACHE_HOME",  || home.join(".cache"));
+
+		let this = Self {
+			config: config.join("bedrock"),
+			data:   data.join("bedrock"),
+			cache:  cache.join("bedrock"),
+		};
+
+		Ok(this)
+	}
+
+	#[cfg(target_family = "windows")]
+	fn find_default() -> Result<Self> {
+		let mut app_data = home_dir().ok_or(Error::MissingDataDir)?;
+
+		app_data.push("AppData");
+
+		let roaming = app_data.join("Roaming").join("Bedrock");
+		let local   = app_data.join("Local").join("Bedrock");
+
+		let this = Self {
+			config: roaming.clone(),
+			data:   roaming,
+			cache:  local,
+		};
+
+		Ok(this)
+	}
+
+	/// Creates the directories and their subdirectories.
+	pub fn create(&self) -> Result<()> {
+		let dirs = [
+			self.config.clone(),
+			self.data.clone(),
+			self.cache.clone(),
+			self.level_dir(),
+			self.rule_dir(),
+			self.snapshot_dir(),
+			self.export_dir(),
+		];
+
+		for dir in dirs {
+			log!(debug, "creating directory at \"{}\"", dir.display());
+
+			create_dir_all(&dir)
+				.map_err(|_| Error::MissingDataDir)?;
+		}
+
+		Ok(())
+	}
+
+	/// Gets the path of the configuration file.
+	#[inline]
+	#[must_use]
+	pub fn config_path(&self) -> PathBuf {
+		self.config.join("config.toml")
+	}
+
+	/// Gets the path of the user's rules.
+	#[inline]
+	#[must_use]
+	pub fn rules_path(&self) -> PathBuf {
+		self.data.join("rules.toml")
+	}
+
+	/// Gets the directory of user levels.
+	#[inline]
+	#[must_use]
+	pub fn level_dir(&self) -> PathBuf {
+		self.data.join("level")
+	}
+
+	/// Gets the directory of custom rules.
+	#[inline]
+	#[must_use]
+	pub fn rule_dir(&self) -> PathBuf {
+		self.data.join("rule")
+	}
+
+	/// Gets the directory of saved snapshots.
+	#[inline]
+	#[must_use]
+	pub fn snapshot_dir(&self) -> PathBuf {
+		self.data.join("snapshot")
+	}
+
+	/// Gets the path of the record of installed bundled levels.
+	///
+	/// This maps the names of installed levels to hashes of their contents, so that edited levels are not overwritten by updates.
+	#[inline]
+	#[must_use]
+	pub fn installed_levels_path(&self) -> PathBuf {
+		self.cache.join("installed.toml")
+	}
+
+	/// Gets the directory of exported images.
+	#[inline]
+	#[must_use]
+	pub fn export_dir(&self) -> PathBuf {
+		self.data.join("export")
+	}
+}
+
+/// Gets an XDG base directory.
+///
+/// Per the specification, the variable is ignored if it is empty or relative.
+#[cfg(target_family = "unix")]
+fn xdg_dir<F: FnOnce() -> PathBuf>(var: &str, default: F) -> PathBuf {
+	var_os(var)
+		.map(PathBuf::from)
+		.filter(|dir| dir.is_absolute())
+		.unwrap_or_else(default)
+}
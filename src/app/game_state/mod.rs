@@ -0,0 +1,58 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+/// The phase of a match.
+///
+/// Durations are counted in ticks, so that they follow the simulation and stop whilst paused.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum GameState {
+	/// Players are gathering for the match.
+	#[default]
+	Lobby,
+
+	/// The round is about to begin.
+	Countdown {
+		ticks: u32,
+	},
+
+	/// A player is taking their turn.
+	Turn {
+		player: usize,
+		ticks:  u32,
+	},
+
+	/// The simulation is calming down after a turn.
+	///
+	/// The ticks denote how long is left before the next turn is started anyway.
+	Settling {
+		previous: usize,
+		ticks:    u32,
+	},
+
+	/// The round has been decided.
+	RoundOver {
+		ticks: u32,
+	},
+
+	/// The match has ended.
+	Results,
+}
+
+impl GameState {
+	/// Gets the index of the player whose turn it is, if any.
+	#[inline]
+	#[must_use]
+	pub const fn active_player(self) -> Option<usize> {
+		if let Self::Turn { player, .. } = self {
+			Some(player)
+		} else {
+			None
+		}
+	}
+
+	/// Tests if a match is in progress.
+	#[inline]
+	#[must_use]
+	pub const fn is_playing(self) -> bool {
+		!matches!(self, Self::Lobby | Self::Results)
+	}
+}
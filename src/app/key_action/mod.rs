@@ -0,0 +1,25 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+/// An action that can be bound to a key.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeyAction {
+	Pause,
+	Quit,
+
+	IncreaseTps,
+	DecreaseTps,
+
+	ExportImage,
+	SaveSnapshot,
+
+	NextLevel,
+	PreviousLevel,
+	ReloadLevel,
+
+	WalkLeft,
+	WalkRight,
+
+	StartGame,
+	EndTurn,
+	Fire,
+}
@@ -0,0 +1,174 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::app::KeyAction;
+
+use serde::{Deserialize, Serialize};
+use winit::keyboard::KeyCode;
+
+/// The keys that are bound to each action.
+///
+/// In the configuration, each key is stored under the name of its field.
+/// Omitted keys keep their default bindings.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+pub struct KeyBindings {
+	#[serde(with = "key")] pub pause: KeyCode,
+	#[serde(with = "key")] pub quit:  KeyCode,
+
+	#[serde(with = "key")] pub increase_tps: KeyCode,
+	#[serde(with = "key")] pub decrease_tps: KeyCode,
+
+	#[serde(with = "key")] pub export_image:  KeyCode,
+	#[serde(with = "key")] pub save_snapshot: KeyCode,
+
+	#[serde(with = "key")] pub next_level:     KeyCode,
+	#[serde(with = "key")] pub previous_level: KeyCode,
+	#[serde(with = "key")] pub reload_level:   KeyCode,
+
+	#[serde(with = "key")] pub walk_left:  KeyCode,
+	#[serde(with = "key")] pub walk_right: KeyCode,
+
+	#[serde(with = "key")] pub start_game: KeyCode,
+	#[serde(with = "key")] pub end_turn:   KeyCode,
+	#[serde(with = "key")] pub fire:       KeyCode,
+}
+
+impl KeyBindings {
+	/// Gets the action bound to a key, if any.
+	///
+	/// If multiple actions are bound to the same key, then the first one in the order of declaration is used.
+	#[must_use]
+	pub fn action(&self, code: KeyCode) -> Option<KeyAction> {
+		let actions = [
+			(self.pause,          KeyAction::Pause),
+			(self.quit,           KeyAction::Quit),
+			(self.increase_tps,   KeyAction::IncreaseTps),
+			(self.decrease_tps,   KeyAction::DecreaseTps),
+			(self.export_image,   KeyAction::ExportImage),
+			(self.save_snapshot,  KeyAction::SaveSnapshot),
+			(self.next_level,     KeyAction::NextLevel),
+			(self.previous_level, KeyAction::PreviousLevel),
+			(self.reload_level,   KeyAction::ReloadLevel),
+			(self.walk_left,      KeyAction::WalkLeft),
+			(self.walk_right,     KeyAction::WalkRight),
+			(self.start_game,     KeyAction::StartGame),
+			(self.end_turn,       KeyAction::EndTurn),
+			(self.fire,           KeyAction::Fire),
+		];
+
+		actions
+			.into_iter()
+			.find(|&(key, _)| key == code)
+			.map(|(_, action)| action)
+	}
+
+	/// Parses the name of a key.
+	///
+	/// Names are the same as those of the [`KeyCode`] variants, e.g. `KeyQ` or `Escape`.
+	/// Only keys that are sensible to bind are supported.
+	#[must_use]
+	pub fn parse_key(name: &str) -> Option<KeyCode> {
+		KEYS
+			.iter()
+			.find(|&&key| Self::key_name(key) == name)
+			.copied()
+	}
+
+	/// Gets the name of a key.
+	///
+	/// See [`parse_key`](Self::parse_key) for more information.
+	#[inline]
+	#[must_use]
+	pub fn key_name(code: KeyCode) -> String {
+		format!("{code:?}")
+	}
+}
+
+impl Default for KeyBindings {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			pause: KeyCode::Escape,
+			quit:  KeyCode::KeyQ,
+
+			increase_tps: KeyCode::Equal,
+			decrease_tps: KeyCode::Minus,
+
+			export_image:  KeyCode::F2,
+			save_snapshot: KeyCode::F5,
+
+			next_level:     KeyCode::PageDown,
+			previous_level: KeyCode::PageUp,
+			reload_level:   KeyCode::KeyR,
+
+			walk_left:  KeyCode::ArrowLeft,
+			walk_right: KeyCode::ArrowRight,
+
+			start_game: KeyCode::Enter,
+			end_turn:   KeyCode::Tab,
+			fire:       KeyCode::Space,
+		}
+	}
+}
+
+/// (De)serialises keys by their names.
+///
+/// See [`KeyBindings::parse_key`] for more information.
+mod key {
+	use crate::app::KeyBindings;
+
+	use serde::de::Error;
+	use serde::{Deserialize, Deserializer, Serializer};
+	use winit::keyboard::KeyCode;
+
+	#[inline]
+	pub fn serialize<S: Serializer>(code: &KeyCode, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&KeyBindings::key_name(*code))
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<KeyCode, D::Error> {
+		let name = String::deserialize(deserializer)?;
+
+		KeyBindings::parse_key(&name)
+			.ok_or_else(|| D::Error::custom(format!("unknown key \"{name}\"")))
+	}
+}
+
+/// The keys that can be bound.
+const KEYS: &[KeyCode] = &[
+	KeyCode::KeyA, KeyCode::KeyB, KeyCode::KeyC, KeyCode::KeyD,
+	KeyCode::KeyE, KeyCode::KeyF, KeyCode::KeyG, KeyCode::KeyH,
+	KeyCode::KeyI, KeyCode::KeyJ, KeyCode::KeyK, KeyCode::KeyL,
+	KeyCode::KeyM, KeyCode::KeyN, KeyCode::KeyO, KeyCode::KeyP,
+	KeyCode::KeyQ, KeyCode::KeyR, KeyCode::KeyS, KeyCode::KeyT,
+	KeyCode::KeyU, KeyCode::KeyV, KeyCode::KeyW, KeyCode::KeyX,
+	KeyCode::KeyY, KeyCode::KeyZ,
+
+	KeyCode::Digit0, KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3,
+	KeyCode::Digit4, KeyCode::Digit5, KeyCode::Digit6, KeyCode::Digit7,
+	KeyCode::Digit8, KeyCode::Digit9,
+
+	KeyCode::F1,  KeyCode::F2,  KeyCode::F3,  KeyCode::F4,
+	KeyCode::F5,  KeyCode::F6,  KeyCode::F7,  KeyCode::F8,
+	KeyCode::F9,  KeyCode::F10, KeyCode::F11, KeyCode::F12,
+
+	KeyCode::ArrowUp,   KeyCode::ArrowDown,
+	KeyCode::ArrowLeft, KeyCode::ArrowRight,
+
+	KeyCode::Backspace,
+	KeyCode::Comma,
+	KeyCode::Delete,
+	KeyCode::End,
+	KeyCode::Enter,
+	KeyCode::Equal,
+	KeyCode::Escape,
+	KeyCode::Home,
+	KeyCode::Insert,
+	KeyCode::Minus,
+	KeyCode::PageDown,
+	KeyCode::PageUp,
+	KeyCode::Period,
+	KeyCode::Slash,
+	KeyCode::Space,
+	KeyCode::Tab,
+];
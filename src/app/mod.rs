@@ -1,12 +1,26 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
 mod app;
+mod args;
+mod command;
 mod config;
+mod dirs;
+mod game_state;
+mod key_action;
+mod key_bindings;
+mod side;
 mod user_event;
 
 pub use app::App;
 
+use args::Args;
+use command::Command;
 use config::Config;
+use dirs::Dirs;
+use game_state::GameState;
+use key_action::KeyAction;
+use key_bindings::KeyBindings;
+use side::Side;
 use user_event::UserEvent;
 
 pub const DEFAULT_PORT: u16 = 0x4274;
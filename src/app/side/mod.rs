@@ -0,0 +1,26 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::player::{Player, Team};
+
+/// A side of a match.
+///
+/// Players on the same team play together, whilst players without a team play alone.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Side {
+	Team(Team),
+
+	/// A player without a team, by their index in the player list.
+	Solo(usize),
+}
+
+impl Side {
+	/// Gets the side of a player.
+	#[must_use]
+	pub fn of(index: usize, player: &Player) -> Self {
+		match player.team {
+			Team::None => Self::Solo(index),
+
+			team => Self::Team(team),
+		}
+	}
+}
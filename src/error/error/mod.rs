@@ -12,16 +12,43 @@ pub enum Error {
 		source:  Box<dyn std::error::Error>,
 	},
 
+	LossyLevel(Box<str>),
+
 	MissingDataDir,
 
 	MissingSpawnChunk,
 
 	UnknownCliArg(Box<str>),
 
+	UnknownConfig {
+		path:   Box<Path>,
+		source: Box<dyn std::error::Error>,
+	},
+
 	UnknownLevel {
 		path:   Box<Path>,
 		source: Box<dyn std::error::Error>,
 	},
+
+	UnknownRules {
+		path:   Box<Path>,
+		source: Box<dyn std::error::Error>,
+	},
+
+	UnknownSnapshot {
+		path:   Box<Path>,
+		source: Box<dyn std::error::Error>,
+	},
+
+	UnwritableImage {
+		path:   Box<Path>,
+		source: Box<dyn std::error::Error>,
+	},
+
+	UnwritableSnapshot {
+		path:   Box<Path>,
+		source: Box<dyn std::error::Error>,
+	},
 }
 
 impl Display for Error {
@@ -37,6 +64,9 @@ impl Display for Error {
 				write!(f, "invalid level field `{field}`: {source}")
 			}
 
+			Self::LossyLevel(ref name)
+			=> write!(f, "level \"{name}\" does not survive serialisation"),
+
 			Self::MissingDataDir
 			=> write!(f, "could not find data directory"),
 
@@ -46,8 +76,23 @@ impl Display for Error {
 			Self::UnknownCliArg(ref arg)
 			=> write!(f, "unknown command line interface \"{arg}\""),
 
+			Self::UnknownConfig { ref path, ref source }
+			=> write!(f, "unable to load config at \"{}\": {source}", path.display()),
+
 			Self::UnknownLevel { ref path, ref source }
 			=> write!(f, "unable to load level at \"{}\": {source}", path.display()),
+
+			Self::UnknownRules { ref path, ref source }
+			=> write!(f, "unable to load rules at \"{}\": {source}", path.display()),
+
+			Self::UnknownSnapshot { ref path, ref source }
+			=> write!(f, "unable to load snapshot at \"{}\": {source}", path.display()),
+
+			Self::UnwritableImage { ref path, ref source }
+			=> write!(f, "unable to export image at \"{}\": {source}", path.display()),
+
+			Self::UnwritableSnapshot { ref path, ref source }
+			=> write!(f, "unable to save snapshot at \"{}\": {source}", path.display()),
 		}
 	}
 }
@@ -60,9 +105,24 @@ impl std::error::Error for Error {
 			Self::InvalidLevel { ref source, .. }
 			=> Some(&**source),
 
+			Self::UnknownConfig { ref source, .. }
+			=> Some(&**source),
+
 			Self::UnknownLevel { ref source, .. }
 			=> Some(&**source),
 
+			Self::UnknownRules { ref source, .. }
+			=> Some(&**source),
+
+			Self::UnknownSnapshot { ref source, .. }
+			=> Some(&**source),
+
+			Self::UnwritableImage { ref source, .. }
+			=> Some(&**source),
+
+			Self::UnwritableSnapshot { ref source, .. }
+			=> Some(&**source),
+
 			_ => None,
 		}
 	}
@@ -74,10 +134,16 @@ impl From<Error> for i32 {
 		match value {
 			| Error::MissingDataDir
 			| Error::UnknownCliArg(_)
+			| Error::UnknownConfig { .. }
 			| Error::UnknownLevel { .. }
+			| Error::UnknownRules { .. }
+			| Error::UnknownSnapshot { .. }
+			| Error::UnwritableImage { .. }
+			| Error::UnwritableSnapshot { .. }
 			=> 0x2,
 
 			| Error::InvalidLevel { .. }
+			| Error::LossyLevel(_)
 			| Error::MissingSpawnChunk
 			=> 0x3,
 		}
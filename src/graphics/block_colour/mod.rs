@@ -0,0 +1,146 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::{Block, Material};
+
+use polywave::www::Html;
+
+/// Gets the display colour of a block.
+///
+/// Each material has four colours, of which the block's seed selects one.
+#[expect(clippy::match_same_arms)]
+#[inline]
+#[must_use]
+pub const fn block_colour(block: Block) -> Html {
+	const DEFAULT_COLOUR: Html = Html::from_u32(0xFF00FFFF);
+
+	let colours: [_; 0x4] = match block.material() {
+		Material::Air => [
+			Html::from_u32(0x00000000),
+			Html::from_u32(0x00000000),
+			Html::from_u32(0x00000000),
+			Html::from_u32(0x00000000),
+		],
+
+		Material::Basalt => [
+			Html::from_u32(0x171717FF),
+			Html::from_u32(0x3A3A3AFF),
+			Html::from_u32(0x2A2A2AFF),
+			Html::from_u32(0x1F1F1FFF),
+		],
+
+		Material::Bedrock => [
+			Html::from_u32(0x252525FF),
+			Html::from_u32(0xD7D7D7FF),
+			Html::from_u32(0x4B4B4BFF),
+			Html::from_u32(0xA2A2A2FF),
+		],
+
+		Material::Clay => [
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+		],
+
+		Material::Dirt => [
+			Html::from_u32(0x4F2D11FF),
+			Html::from_u32(0x4F341DFF),
+			Html::from_u32(0x53361DFF),
+			Html::from_u32(0x4C2F16FF),
+		],
+
+		Material::Fire => [
+			Html::from_u32(0xFF5A00FF),
+			Html::from_u32(0xFF9A00FF),
+			Html::from_u32(0xFFCE00FF),
+			Html::from_u32(0xFFE808FF),
+		],
+
+		Material::Glass => [
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+		],
+
+		Material::Granite => [
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+		],
+
+		Material::Grass => [
+			Html::from_u32(0x9AB34EFF),
+			Html::from_u32(0x6D913FFF),
+			Html::from_u32(0x98AA39FF),
+			Html::from_u32(0xB3CC60FF),
+		],
+
+		Material::Gravel => [
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+		],
+
+		Material::Ice => [
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+		],
+
+		Material::Limestone => [
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+		],
+
+		Material::Magma => [
+			Html::from_u32(0xFF4800FF),
+			Html::from_u32(0xFF8200FF),
+			Html::from_u32(0xFFA000FF),
+			Html::from_u32(0xFEB300FF),
+		],
+
+		Material::Marble => [
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+		],
+
+		Material::Sand => [
+			Html::from_u32(0xF5D88FFF),
+			Html::from_u32(0xF8E5B4FF),
+			Html::from_u32(0xFCEDC5FF),
+			Html::from_u32(0xF7D479FF),
+		],
+
+		Material::Rock => [
+			Html::from_u32(0x6D6D6DFF),
+			Html::from_u32(0x797979FF),
+			Html::from_u32(0x616161FF),
+			Html::from_u32(0x595959FF),
+		],
+
+		Material::Water => [
+			Html::from_u32(0x286DC3BF),
+			Html::from_u32(0x2565B8BF),
+			Html::from_u32(0x1F69BCBF),
+			Html::from_u32(0x2566B4BF),
+		],
+
+		Material::Wood => [
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+			DEFAULT_COLOUR,
+		],
+	};
+
+	let seed = block.seed();
+	colours[seed as usize]
+}
@@ -1,6 +1,6 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
-use crate::graphics::InitGraphicsContext;
+use crate::graphics::{InitGraphicsContext, WindowSettings};
 use crate::log::log;
 
 use std::hint::cold_path;
@@ -25,13 +25,13 @@ impl GraphicsContext {
 
 	#[expect(unused)]
 	#[inline(always)]
-	pub fn init(&mut self, event_loop: &ActiveEventLoop) {
-		Self::init_with(self, event_loop, |_| { });
+	pub fn init(&mut self, event_loop: &ActiveEventLoop, settings: WindowSettings) {
+		Self::init_with(self, event_loop, settings, |_| { });
 	}
 
 	#[inline]
 	#[track_caller]
-	pub fn init_with<F: FnOnce(&mut InitGraphicsContext)>(&mut self, event_loop: &ActiveEventLoop, op: F) {
+	pub fn init_with<F: FnOnce(&mut InitGraphicsContext)>(&mut self, event_loop: &ActiveEventLoop, settings: WindowSettings, op: F) {
 		if self.is_init() {
 			log!(note, "graphics context is already initialised");
 
@@ -43,7 +43,7 @@ impl GraphicsContext {
 		// This should only happen once per run.
 		cold_path();
 
-		let mut context = InitGraphicsContext::new(event_loop);
+		let mut context = InitGraphicsContext::new(event_loop, settings);
 
 		op(&mut context);
 
@@ -31,7 +31,7 @@ pub struct InitGraphicsContext {
 }
 
 impl InitGraphicsContext {
-	const DEFAULT_SIZE: (u32, u32) = (0x280, 0x1E0);
+	pub const DEFAULT_SIZE: (u32, u32) = (0x280, 0x1E0);
 
 	const TEXTURE_WIDTH: u32 = {
 		if Self::DEFAULT_SIZE.0 >= Self::DEFAULT_SIZE.1 {
@@ -1,6 +1,6 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
-use crate::graphics::{InitGraphicsContext, Vertex};
+use crate::graphics::{InitGraphicsContext, Vertex, WindowSettings};
 
 use crate::log::log;
 use crate::version::Version;
@@ -10,17 +10,17 @@ use std::pin::Pin;
 use wgpu::util::{BufferInitDescriptor, DeviceExt};
 use winit::dpi::PhysicalSize;
 use winit::event_loop::ActiveEventLoop;
-use winit::window::WindowAttributes;
+use winit::window::{Fullscreen, WindowAttributes};
 use zerocopy::IntoBytes;
 
 impl InitGraphicsContext {
 	#[must_use]
-	pub fn new(event_loop: &ActiveEventLoop) -> Self {
+	pub fn new(event_loop: &ActiveEventLoop, settings: WindowSettings) -> Self {
 		log!(debug, "creating new graphics context");
 
 		let size = PhysicalSize {
-			width:  Self::DEFAULT_SIZE.0,
-			height: Self::DEFAULT_SIZE.1,
+			width:  settings.size.0,
+			height: settings.size.1,
 		};
 
 		log!(debug, "opening window");
@@ -28,9 +28,14 @@ impl InitGraphicsContext {
 		let window = {
 			let title = format!("Bedrock {}", Version::CURRENT);
 
+			let fullscreen = settings
+				.is_fullscreen
+				.then_some(Fullscreen::Borderless(None));
+
 			let attrs = WindowAttributes::default()
 				.with_inner_size(size)
-				.with_min_inner_size(size)
+				.with_min_inner_size(PhysicalSize::from(Self::DEFAULT_SIZE))
+				.with_fullscreen(fullscreen)
 				.with_title(&title);
 
 			match event_loop.create_window(attrs) {
@@ -0,0 +1,126 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::graphics::block_colour;
+use crate::map::Map;
+
+use polywave::www::Html;
+use std::io::Write;
+use zerocopy::IntoBytes;
+
+/// An image of an entire map.
+///
+/// Unlike [`draw_map`](crate::graphics::InitGraphicsContext::draw_map), this is rendered without the GPU and independently of the view.
+/// Each block is drawn as a square of pixels, and blocks are composited onto the level background.
+#[derive(Clone, Debug)]
+pub struct MapImage {
+	width:  u32,
+	height: u32,
+
+	/// The RGBA pixels of the image, from the top row down.
+	data: Box<[u8]>,
+}
+
+impl MapImage {
+	/// The largest allowed scale.
+	pub const MAX_SCALE: u32 = 0x10;
+
+	/// The largest allowed size of the pixel data, in bytes.
+	///
+	/// This is 256 MiB, which suffices for e.g. a `4096*4096` image.
+	pub const MAX_LEN: usize = 0x1000_0000;
+
+	/// Renders a map.
+	///
+	/// The scale denotes the width and height of each block in pixels, and is clamped to <code>1..=[MAX_SCALE](Self::MAX_SCALE)</code>.
+	///
+	/// If the pixel data would exceed [`MAX_LEN`](Self::MAX_LEN) bytes, then [`None`] is returned instead of allocating it.
+	#[must_use]
+	pub fn render(map: &Map, background: Html, scale: u32) -> Option<Self> {
+		let scale = scale.clamp(0x1, Self::MAX_SCALE);
+
+		let (map_width, map_height) = map.size().get();
+
+		let width  = map_width.checked_mul(scale)?;
+		let height = map_height.checked_mul(scale)?;
+
+		let row_len = usize::try_from(width).ok()?.checked_mul(0x4)?;
+		let len     = row_len.checked_mul(usize::try_from(height).ok()?)?;
+
+		if len > Self::MAX_LEN {
+			return None;
+		}
+
+		let mut data = vec![0x0u8; len].into_boxed_slice();
+
+		let background: [u8; 0x4] = background.as_bytes().try_into().unwrap();
+
+		for (x, column) in map.as_slice().chunks_exact(map_height as usize).enumerate() {
+			for (y, &block) in column.iter().enumerate() {
+				let colour: [u8; 0x4] = block_colour(block).as_bytes().try_into().unwrap();
+
+				let colour = blend(colour, background);
+
+				// Images go from the top down, whilst maps go
+				// from the bottom up.
+
+				let row = (map_height as usize - 0x1 - y) * scale as usize;
+				let col = x * scale as usize;
+
+				for row in row..row + scale as usize {
+					let start = row * row_len + col * 0x4;
+					let end   = start + scale as usize * 0x4;
+
+					for pixel in data[start..end].chunks_exact_mut(0x4) {
+						pixel.copy_from_slice(&colour);
+					}
+				}
+			}
+		}
+
+		Some(Self { width, height, data })
+	}
+
+	#[inline(always)]
+	#[must_use]
+	pub const fn width(&self) -> u32 {
+		self.width
+	}
+
+	#[inline(always)]
+	#[must_use]
+	pub const fn height(&self) -> u32 {
+		self.height
+	}
+
+	/// Encodes the image as PNG.
+	pub fn write_png<W: Write>(&self, output: W) -> Result<(), png::EncodingError> {
+		let mut encoder = png::Encoder::new(output, self.width, self.height);
+
+		encoder.set_color(png::ColorType::Rgba);
+		encoder.set_depth(png::BitDepth::Eight);
+
+		let mut writer = encoder.write_header()?;
+
+		writer.write_image_data(&self.data)?;
+		writer.finish()
+	}
+}
+
+/// Blends a colour over another.
+#[must_use]
+fn blend(src: [u8; 0x4], dst: [u8; 0x4]) -> [u8; 0x4] {
+	let alpha = u16::from(src[0x3]);
+
+	let mix = |src: u8, dst: u8| -> u8 {
+		let value = u16::from(src) * alpha + u16::from(dst) * (0xFF - alpha);
+
+		(value / 0xFF) as u8
+	};
+
+	[
+		mix(src[0x0], dst[0x0]),
+		mix(src[0x1], dst[0x1]),
+		mix(src[0x2], dst[0x2]),
+		mix(0xFF,     dst[0x3]),
+	]
+}
@@ -1,12 +1,18 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
+mod block_colour;
 mod graphics_context;
 mod init_graphics_context;
+mod map_image;
 mod vec2;
 mod vertex;
+mod window_settings;
 
+pub use block_colour::block_colour;
 pub use graphics_context::GraphicsContext;
 pub use init_graphics_context::InitGraphicsContext;
+pub use map_image::MapImage;
 pub use vec2::Vec2;
+pub use window_settings::WindowSettings;
 
 use vertex::Vertex;
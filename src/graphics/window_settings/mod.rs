@@ -0,0 +1,26 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::graphics::InitGraphicsContext;
+
+/// Settings for opening the game window.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WindowSettings {
+	/// The inner size of the window, in physical pixels.
+	///
+	/// The window may be shrunk down to [`DEFAULT_SIZE`](InitGraphicsContext::DEFAULT_SIZE) regardless of this.
+	pub size: (u32, u32),
+
+	/// Denotes whether the window is borderless fullscreen.
+	pub is_fullscreen: bool,
+}
+
+impl Default for WindowSettings {
+	#[inline(always)]
+	fn default() -> Self {
+		Self {
+			size: InitGraphicsContext::DEFAULT_SIZE,
+
+			is_fullscreen: false,
+		}
+	}
+}
@@ -0,0 +1,140 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::Material;
+
+use std::error::Error;
+use std::io::Read;
+
+/// A level bitmap.
+///
+/// Bitmaps are an alternative to defining terrain by [layers](crate::level::Layer).
+/// Each pixel in a bitmap denotes a single material, and the bitmap is scaled to fit the map when generated.
+///
+/// Bitmaps are usually decoded from PNG images using a palette of colours.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bitmap {
+	width:  u32,
+	height: u32,
+
+	/// The materials of the bitmap, from the top row down.
+	materials: Box<[Material]>,
+}
+
+impl Bitmap {
+	/// Constructs a new bitmap from its materials.
+	///
+	/// The materials must be laid out row by row, starting from the top.
+	/// If the amount of materials does not match the provided size, or if either axis is zero, then this constructor returns [`None`].
+	#[inline]
+	#[must_use]
+	pub fn new(width: u32, height: u32, materials: Box<[Material]>) -> Option<Self> {
+		if width == 0x0 || height == 0x0 {
+			return None;
+		}
+
+		if Some(materials.len()) != (width as usize).checked_mul(height as usize) {
+			return None;
+		}
+
+		let this = Self { width, height, materials };
+		Some(this)
+	}
+
+	/// Decodes a bitmap from a PNG image.
+	///
+	/// The palette maps RGB colours to materials.
+	/// Fully-transparent pixels are always decoded as [`Air`](Material::Air), and any other colour that is not in the palette is an error.
+	pub fn decode_png<R: Read>(input: R, palette: &[([u8; 0x3], Material)]) -> Result<Self, Box<dyn Error>> {
+		let mut decoder = png::Decoder::new(input);
+
+		decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+
+		let mut reader = decoder.read_info()?;
+
+		let mut buf = vec![0x0; reader.output_buffer_size()];
+
+		let info = reader.next_frame(&mut buf)?;
+
+		let (width, height) = (info.width, info.height);
+
+		let channels = match info.color_type {
+			png::ColorType::Grayscale      => 0x1,
+			png::ColorType::GrayscaleAlpha => 0x2,
+			png::ColorType::Rgb            => 0x3,
+			png::ColorType::Rgba           => 0x4,
+
+			png::ColorType::Indexed => return Err("indexed colours were not expanded".into()),
+		};
+
+		let mut materials = Vec::with_capacity(width as usize * height as usize);
+
+		for row in buf.chunks_exact(info.line_size).take(height as usize) {
+			for pixel in row.chunks_exact(channels).take(width as usize) {
+				let (colour, alpha) = match channels {
+					0x1 => ([pixel[0x0]; 0x3], 0xFF),
+					0x2 => ([pixel[0x0]; 0x3], pixel[0x1]),
+					0x3 => ([pixel[0x0], pixel[0x1], pixel[0x2]], 0xFF),
+
+					_ => ([pixel[0x0], pixel[0x1], pixel[0x2]], pixel[0x3]),
+				};
+
+				if alpha == 0x0 {
+					materials.push(Material::Air);
+					continue;
+				}
+
+				let Some(&(_, material)) = palette.iter().find(|&&(key, _)| key == colour) else {
+					let [r, g, b] = colour;
+
+					return Err(format!("colour `#{r:02X}{g:02X}{b:02X}` is not in the palette").into());
+				};
+
+				materials.push(material);
+			}
+		}
+
+		Self::new(width, height, materials.into())
+			.ok_or_else(|| "bitmap has invalid dimensions".into())
+	}
+
+	#[inline(always)]
+	#[must_use]
+	pub const fn width(&self) -> u32 {
+		self.width
+	}
+
+	#[inline(always)]
+	#[must_use]
+	pub const fn height(&self) -> u32 {
+		self.height
+	}
+
+	/// Gets the material at the given coordinates.
+	///
+	/// The coordinates are counted from the top-left corner.
+	#[inline]
+	#[must_use]
+	pub fn get(&self, x: u32, y: u32) -> Option<Material> {
+		if x >= self.width || y >= self.height {
+			return None;
+		}
+
+		let index = y as usize * self.width as usize + x as usize;
+		self.materials.get(index).copied()
+	}
+
+	/// Samples the bitmap at the given coordinates relative to its size.
+	///
+	/// Both coordinates are expected to be in the range `0.0..1.0`, with the vertical coordinate counted from the bottom up.
+	/// The nearest pixel is used.
+	#[must_use]
+	pub fn sample(&self, x: f64, y: f64) -> Material {
+		let x = (x * f64::from(self.width)) as u32;
+		let y = ((1.0 - y) * f64::from(self.height)) as u32;
+
+		let x = x.min(self.width  - 0x1);
+		let y = y.min(self.height - 0x1);
+
+		self.get(x, y).unwrap_or_default()
+	}
+}
@@ -33,6 +33,26 @@ impl Block {
 		self.0 = value;
 	}
 
+	/// Converts a raw representation into a block.
+	///
+	/// If the raw material does not denote any material, then this function returns [`None`].
+	#[inline]
+	#[must_use]
+	pub const fn from_u8(value: u8) -> Option<Self> {
+		if Material::new(value & 0b00111111).is_none() {
+			return None;
+		}
+
+		Some(Self(value))
+	}
+
+	/// Converts the block into its raw representation.
+	#[inline(always)]
+	#[must_use]
+	pub const fn to_u8(self) -> u8 {
+		self.0
+	}
+
 	#[inline]
 	#[must_use]
 	pub const fn material(self) -> Material {
@@ -68,16 +88,16 @@ impl Block {
 			Material::Clay      => BlockTags::NONE,
 			Material::Dirt      => BlockTags::NONE,
 			Material::Granite   => BlockTags::STATIC,
-			Material::Grass     => BlockTags::NONE,
+			Material::Grass     => BlockTags::COMBUSTIBLE,
 			Material::Gravel    => BlockTags::NONE,
 			Material::Limestone => BlockTags::STATIC,
 			Material::Magma     => BlockTags::HOT.union(BlockTags::LIQUID),
 			Material::Marble    => BlockTags::STATIC,
 			Material::Sand      => BlockTags::NONE,
 			Material::Rock     => BlockTags::STATIC,
-			Material::Water     => BlockTags::LIQUID,
+			Material::Water     => BlockTags::LIQUID.union(BlockTags::VOLATILE),
 			Material::Ice       => BlockTags::COLD.union(BlockTags::STICKY),
-			Material::Wood      => BlockTags::STICKY,
+			Material::Wood      => BlockTags::COMBUSTIBLE.union(BlockTags::STICKY),
 			Material::Glass     => BlockTags::STICKY,
 			Material::Fire      => BlockTags::HOT,
 		}
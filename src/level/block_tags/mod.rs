@@ -1,5 +1,7 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
+use crate::level::BlockTagsFromStrError;
+
 use std::fmt::{self, Debug, Formatter};
 use std::ops::{
 	BitAnd,
@@ -12,6 +14,7 @@ use std::ops::{
 	Sub,
 	SubAssign,
 };
+use std::str::FromStr;
 
 /// A list of block tags.
 ///
@@ -101,6 +104,12 @@ impl BlockTags {
 	pub const fn contains(self, other: Self) -> bool {
 		self.0 | other.0 == self.0
 	}
+
+	#[inline(always)]
+	#[must_use]
+	pub const fn intersects(self, other: Self) -> bool {
+		self.0 & other.0 != 0x0
+	}
 }
 
 impl BitAnd for BlockTags {
@@ -158,6 +167,30 @@ impl Debug for BlockTags {
 	}
 }
 
+impl FromStr for BlockTags {
+	type Err = BlockTagsFromStrError;
+
+	/// Parses a single tag.
+	#[inline]
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"none"        => Ok(Self::NONE),
+			"static"      => Ok(Self::STATIC),
+			"liquid"      => Ok(Self::LIQUID),
+			"hot"         => Ok(Self::HOT),
+			"cold"        => Ok(Self::COLD),
+			"empty"       => Ok(Self::EMPTY),
+			"divine"      => Ok(Self::DIVINE),
+			"sticky"      => Ok(Self::STICKY),
+			"combustible" => Ok(Self::COMBUSTIBLE),
+			"volatile"    => Ok(Self::VOLATILE),
+			"all"         => Ok(Self::ALL),
+
+			_ => Err(BlockTagsFromStrError { name: s.into() })
+		}
+	}
+}
+
 impl Not for BlockTags {
 	type    Output = Self;
 
@@ -0,0 +1,18 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use std::fmt::{self, Display, Formatter};
+
+/// A [block tag](crate::level::BlockTags) could not be parsed from a string.
+#[derive(Debug, Eq, PartialEq)]
+pub struct BlockTagsFromStrError {
+	pub name: Box<str>,
+}
+
+impl std::error::Error for BlockTagsFromStrError { }
+
+impl Display for BlockTagsFromStrError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "unknown block tag \"{}\"", self.name)
+	}
+}
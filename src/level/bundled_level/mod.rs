@@ -0,0 +1,28 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+/// A level shipped with the game.
+///
+/// Bundled levels are installed into the level directory, so that they may serve as templates for custom levels.
+/// Each level has a revision which is increased whenever the shipped file changes; installed levels are only replaced if their revision is older.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BundledLevel {
+	/// The file stem of the level.
+	pub name: &'static str,
+
+	pub revision: u32,
+
+	/// The TOML source of the level.
+	pub source: &'static str,
+}
+
+impl BundledLevel {
+	/// All bundled levels.
+	pub const ALL: &'static [Self] = &[
+		Self { name: "field",     revision: 0x1, source: include_str!("field.toml") },
+		Self { name: "mountain",  revision: 0x2, source: include_str!("mountain.toml") },
+		Self { name: "valley",    revision: 0x2, source: include_str!("valley.toml") },
+		Self { name: "lake",      revision: 0x1, source: include_str!("lake.toml") },
+		Self { name: "lava_lake", revision: 0x1, source: include_str!("lava_lake.toml") },
+		Self { name: "test",      revision: 0x1, source: include_str!("test.toml") },
+	];
+}
@@ -1,6 +1,6 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
-use crate::level::Layer;
+use crate::level::{Layer, Noise};
 
 use std::borrow::Cow;
 
@@ -8,7 +8,7 @@ use std::borrow::Cow;
 ///
 /// Levels are made up of a non-zero amount of segments that are called *chunks*.
 /// Each chunk defines additional *layers* itself (see [`Layer`]).
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Chunk {
 	/// The width of the chunk, relative to the map width.
 	pub width: f64,
@@ -16,6 +16,17 @@ pub struct Chunk {
 	/// Denotes whether players can spawn in the chunk.
 	pub is_spawnable: bool,
 
+	/// The fraction of the chunk's width, at each side, that is blended with neighbouring chunks.
+	///
+	/// The surface is interpolated smoothly towards the heights of neighbouring chunks within this distance of the chunk's edges.
+	/// A value of `0.0` yields vertical walls.
+	pub blend: f64,
+
+	/// The noise applied to the chunk's surface, if any.
+	///
+	/// The chunk's layers are stretched or squashed to follow the noise.
+	pub noise: Option<Noise>,
+
 	/// The layers of the chunk.
 	pub layers: Cow<'static, [Layer]>,
 }
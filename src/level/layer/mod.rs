@@ -1,13 +1,16 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
-use crate::level::Material;
+use crate::level::{Material, Noise};
 
 /// A level layer.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Layer {
 	/// The height of the layer, relative to the map height.
 	pub height: f64,
 
 	/// The material used in the layer.
 	pub material: Material,
+
+	/// The noise applied to the layer's height, if any.
+	pub noise: Option<Noise>,
 }
@@ -0,0 +1,26 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::Level;
+
+use std::ops::Range;
+
+impl Level {
+	/// Gets the columns covered by each chunk.
+	///
+	/// Chunk widths are truncated to whole columns, so the last columns of the map may not be covered by any chunk.
+	#[must_use]
+	pub fn chunk_bounds(&self, map_width: u32) -> Vec<Range<u32>> {
+		self
+			.chunks
+			.iter()
+			.scan(0x0u32, |start, chunk| {
+				let end = *start + (f64::from(map_width) * chunk.width) as u32;
+
+				let bounds = *start..end;
+				*start = end;
+
+				Some(bounds)
+			})
+			.collect()
+	}
+}
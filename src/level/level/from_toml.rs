@@ -0,0 +1,249 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::error::{Error, Result};
+use crate::level::{Bitmap, Chunk, Layer, Level, Noise};
+use crate::log::log;
+
+use polywave::www::Html;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use zerocopy::IntoBytes;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(super) struct LevelHelper {
+	pub level: LevelLevelHelper,
+
+	pub chunk: Vec<LevelChunkHelper>,
+
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+	pub palette: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(super) struct LevelLevelHelper {
+	pub name:        String,
+	pub creatour:    String,
+	pub description: String,
+
+	pub background: String,
+
+	pub rules: Option<String>,
+
+	pub bitmap: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(super) struct LevelChunkHelper {
+	pub width: f64,
+
+	pub is_spawnable: bool,
+
+	#[serde(default)]
+	pub blend: f64,
+
+	pub noise: Option<LevelNoiseHelper>,
+
+	#[serde(default)]
+	pub layer: Vec<LevelChunkLayerHelper>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(super) struct LevelChunkLayerHelper {
+	pub height:   f64,
+	pub material: String,
+
+	pub noise: Option<LevelNoiseHelper>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(super) struct LevelNoiseHelper {
+	pub amplitude: f64,
+	pub frequency: f64,
+
+	#[serde(default = "default_octaves")]
+	pub octaves: u8,
+}
+
+impl From<LevelNoiseHelper> for Noise {
+	#[inline]
+	fn from(value: LevelNoiseHelper) -> Self {
+		Self {
+			amplitude: value.amplitude,
+			frequency: value.frequency,
+			octaves:   value.octaves,
+		}
+	}
+}
+
+impl From<Noise> for LevelNoiseHelper {
+	#[inline]
+	fn from(value: Noise) -> Self {
+		Self {
+			amplitude: value.amplitude,
+			frequency: value.frequency,
+			octaves:   value.octaves,
+		}
+	}
+}
+
+impl Level {
+	/// Parses a level from TOML.
+	///
+	/// The path denotes the level file and is used for locating bitmaps and for reporting errors.
+	/// The level is not validated.
+	pub fn from_toml(source: &str, path: &Path) -> Result<Self> {
+		let parse_chunk_layer = |i: usize, j: usize, helper: LevelChunkLayerHelper| -> Result<Layer> {
+			let material = helper
+				.material
+				.parse()
+				.map_err(|e| Error::InvalidLevel {
+					section: Some(format!("chunk[{i}].layer[{j}].").into()),
+					field:   "material".into(),
+					source:  Box::new(e),
+				})?;
+
+			let layer = Layer {
+				height:   helper.height,
+				material,
+
+				noise: helper.noise.map(Into::into),
+			};
+
+			Ok(layer)
+		};
+
+		let parse_chunk = |i: usize, helper: LevelChunkHelper| -> Result<Chunk> {
+			let layers = helper
+				.layer
+				.into_iter()
+				.enumerate()
+				.map(|(j, layer)| parse_chunk_layer(i, j, layer))
+				.collect::<Result<_>>()?;
+
+			let chunk = Chunk {
+				width: helper.width,
+
+				is_spawnable: helper.is_spawnable,
+
+				blend: helper.blend,
+
+				noise: helper.noise.map(Into::into),
+
+				layers,
+			};
+
+			Ok(chunk)
+		};
+
+		let helper = toml::from_str::<LevelHelper>(source)
+			.map_err(|e| Error::UnknownLevel { path: path.into(), source: Box::new(e) })?;
+
+		let chunks = helper
+			.chunk
+			.into_iter()
+			.enumerate()
+			.map(|(i, chunk)| parse_chunk(i, chunk))
+			.collect::<Result<_>>()?;
+
+		let background = helper
+			.level
+			.background
+			.parse()
+			.map_err(|e| Error::InvalidLevel {
+				section: Some("level.".into()),
+				field:   "background".into(),
+				source:  Box::new(e),
+			})?;
+
+		let bitmap = helper
+			.level
+			.bitmap
+			.map(|name| {
+				let bitmap_path = bitmap_path(path, &name)?;
+
+				load_bitmap(&bitmap_path, &helper.palette)
+					.map_err(|e| Error::UnknownLevel { path: bitmap_path.into(), source: e })
+			})
+			.transpose()?;
+
+		let this = Self {
+			name:        helper.level.name.into(),
+			creatour:    helper.level.creatour.into(),
+			description: helper.level.description.into(),
+
+			background,
+
+			rules: helper.level.rules.map(Into::into),
+
+			chunks,
+
+			bitmap,
+		};
+
+		Ok(this)
+	}
+
+	/// Gets the path of the bitmap used by a level, if any.
+	///
+	/// This only parses as much of the level as is needed, and is used for watching the bitmap for changes.
+	/// Invalid levels and bitmap names yield [`None`].
+	#[must_use]
+	pub fn bitmap_file(source: &str, path: &Path) -> Option<PathBuf> {
+		let helper = toml::from_str::<LevelHelper>(source).ok()?;
+
+		let name = helper.level.bitmap?;
+
+		bitmap_path(path, &name).ok()
+	}
+}
+
+/// Gets the path of a level's bitmap.
+///
+/// Bitmaps are relative to the level directory, and their names must be [valid identifiers](Level::is_valid_id) followed by `.png`.
+/// This keeps levels -- which may be shared -- from reading files outside of the level directory.
+fn bitmap_path(path: &Path, name: &str) -> Result<PathBuf> {
+	if !name.strip_suffix(".png").is_some_and(Level::is_valid_id) {
+		return Err(Error::InvalidLevel {
+			section: Some("level.".into()),
+			field:   "bitmap".into(),
+			source:  format!("`{name}` is not a valid bitmap name").into(),
+		});
+	}
+
+	Ok(path.with_file_name(name))
+}
+
+fn load_bitmap(path: &Path, palette: &BTreeMap<String, String>) -> std::result::Result<Bitmap, Box<dyn std::error::Error>> {
+	log!(debug, "loading bitmap at \"{}\"", path.display());
+
+	let palette = palette
+		.iter()
+		.map(|(colour, material)| {
+			let colour: Html = colour.parse()?;
+			let colour: [u8; 0x4] = colour.as_bytes().try_into()?;
+
+			let [r, g, b, _] = colour;
+
+			let material = material.parse()?;
+
+			Ok(([r, g, b], material))
+		})
+		.collect::<std::result::Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+
+	let file = File::open(path)?;
+
+	let bitmap = Bitmap::decode_png(BufReader::new(file), &palette)?;
+
+	log!(note, "bitmap is `{}*{}` pixels", bitmap.width(), bitmap.height());
+
+	Ok(bitmap)
+}
+
+#[inline(always)]
+#[must_use]
+const fn default_octaves() -> u8 {
+	0x1
+}
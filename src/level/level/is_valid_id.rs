@@ -0,0 +1,14 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::Level;
+
+impl Level {
+	/// Tests if a string is usable as the identifier of a level, of a set of rules, or -- without its extension -- of a bitmap.
+	///
+	/// Identifiers are the names of files in the data directory, and may therefore not be empty, contain path separators, or contain `..`.
+	/// This keeps levels from referring to files outside of the data directory.
+	#[must_use]
+	pub fn is_valid_id(id: &str) -> bool {
+		!id.is_empty() && !id.contains("..") && !id.contains(['/', '\\', ':', '\0'])
+	}
+}
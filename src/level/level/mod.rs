@@ -1,13 +1,21 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
+#[cfg(test)]
+mod test;
+
+mod chunk_bounds;
+mod from_toml;
+mod is_valid_id;
 mod load_builtin;
+mod to_toml;
+mod validate;
 
-use crate::level::Chunk;
+use crate::level::{Bitmap, Chunk};
 
 use polywave::www::Html;
 use std::borrow::Cow;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Level {
 	pub name:        Cow<'static, str>,
 	pub creatour:    Cow<'static, str>,
@@ -15,12 +23,21 @@ pub struct Level {
 
 	pub background: Html,
 
+	/// The name of the level's custom rules, if any.
+	pub rules: Option<Cow<'static, str>>,
+
 	pub chunks: Cow<'static, [Chunk]>,
+
+	/// The bitmap of the level's terrain, if any.
+	///
+	/// If set, then the terrain is generated from the bitmap instead of from the chunk layers.
+	/// The chunks are still used for their widths and spawnability.
+	pub bitmap: Option<Bitmap>,
 }
 
 impl Default for Level {
-	#[inline(always)]
+	#[inline]
 	fn default() -> Self {
-		Self::LAVA_LAKE
+		Self::load_builtin("lava_lake").expect("default level should be built in")
 	}
 }
@@ -0,0 +1,39 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::{BundledLevel, Level};
+
+use std::path::Path;
+
+/// Writes a level to TOML and reads it back.
+fn round_trip(name: &str, level: &Level) -> Level {
+	let path = Path::new(name).with_extension("toml");
+
+	let source = level.to_toml().expect("level should be serialisable");
+
+	Level::from_toml(&source, &path).expect("serialised level should be parsable")
+}
+
+#[test]
+fn levels_survive_serialisation() {
+	// Built-in levels are parsed from their bundled
+	// copies, so this covers all of them.
+
+	for bundled in BundledLevel::ALL {
+		let path = Path::new(bundled.name).with_extension("toml");
+
+		let level = Level::from_toml(bundled.source, &path).expect("bundled level should be parsable");
+
+		assert_eq!(round_trip(bundled.name, &level), level, "level \"{}\" changed", bundled.name);
+	}
+}
+
+#[test]
+fn builtin_levels_are_valid() {
+	for &name in Level::BUILTIN_NAMES {
+		let level = Level::load_builtin(name).expect("built-in level should exist");
+
+		level
+			.validate()
+			.unwrap_or_else(|e| panic!("built-in level \"{name}\" is invalid: {e}"));
+	}
+}
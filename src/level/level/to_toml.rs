@@ -0,0 +1,92 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::error::{Error, Result};
+use crate::level::Level;
+use crate::level::level::from_toml::{
+	LevelChunkHelper,
+	LevelChunkLayerHelper,
+	LevelHelper,
+	LevelLevelHelper,
+};
+
+use zerocopy::IntoBytes;
+
+impl Level {
+	/// Serialises the level to TOML.
+	///
+	/// The output uses the same schema as [`from_toml`](Self::from_toml).
+	///
+	/// Bitmaps are kept in separate files which are not known to the level.
+	/// Levels with bitmaps can therefore not be serialised, and [`LossyLevel`](Error::LossyLevel) is returned for them.
+	pub fn to_toml(&self) -> Result<String> {
+		if self.bitmap.is_some() {
+			return Err(Error::LossyLevel(self.name.as_ref().into()));
+		}
+
+		let background = {
+			let [r, g, b, a]: [u8; 0x4] = self
+				.background
+				.as_bytes()
+				.try_into()
+				.expect("colour should be four bytes");
+
+			if a == 0xFF {
+				format!("#{r:02X}{g:02X}{b:02X}")
+			} else {
+				format!("#{r:02X}{g:02X}{b:02X}{a:02X}")
+			}
+		};
+
+		let chunk = self
+			.chunks
+			.iter()
+			.map(|chunk| {
+				let layer = chunk
+					.layers
+					.iter()
+					.map(|layer| LevelChunkLayerHelper {
+						height:   layer.height,
+						material: layer.material.to_string(),
+
+						noise: layer.noise.map(Into::into),
+					})
+					.collect();
+
+				LevelChunkHelper {
+					width: chunk.width,
+
+					is_spawnable: chunk.is_spawnable,
+
+					blend: chunk.blend,
+
+					noise: chunk.noise.map(Into::into),
+
+					layer,
+				}
+			})
+			.collect();
+
+		let helper = LevelHelper {
+			level: LevelLevelHelper {
+				name:        self.name.to_string(),
+				creatour:    self.creatour.to_string(),
+				description: self.description.to_string(),
+
+				background,
+
+				rules: self.rules.as_deref().map(Into::into),
+
+				bitmap: None,
+			},
+
+			chunk,
+
+			palette: Default::default(),
+		};
+
+		// All fields are representable in TOML.
+		let source = toml::to_string_pretty(&helper).expect("unable to serialise level");
+
+		Ok(source)
+	}
+}
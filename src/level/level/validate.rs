@@ -0,0 +1,110 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::error::{Error, Result};
+use crate::level::{Level, Noise};
+
+/// The tolerance used when summing relative sizes.
+///
+/// This allows for sizes such as thirds, whose sums are not exact.
+const SUM_TOLERANCE: f64 = 1.0e-9;
+
+impl Level {
+	/// Validates the level.
+	///
+	/// This tests that:
+	///
+	/// * There is at least one chunk, but no more than `255`,
+	/// * At least one chunk is spawnable,
+	/// * The name of the level's rules, if any, is a [valid identifier](Self::is_valid_id),
+	/// * Chunk widths and layer heights are in the range `0.0..=1.0`,
+	/// * Chunk widths -- and the layer heights of each chunk -- sum to at most `1.0`,
+	/// * Chunk blends are in the range `0.0..=0.5`, and
+	/// * Noise amplitudes are in the range `0.0..=1.0`, frequencies are finite and non-negative, and octaves are in the range `1..=8`.
+	///
+	/// Any violation is reported as [`InvalidLevel`](Error::InvalidLevel) with the offending field.
+	pub fn validate(&self) -> Result<()> {
+		let invalid = |section: Option<String>, field: &str, message: String| Error::InvalidLevel {
+			section: section.map(Into::into),
+			field:   field.into(),
+			source:  message.into(),
+		};
+
+		if let Some(rules) = self.rules.as_deref().filter(|&rules| !Self::is_valid_id(rules)) {
+			return Err(invalid(Some("level.".into()), "rules", format!("`{rules}` is not a valid rules name")));
+		}
+
+		if self.chunks.is_empty() {
+			return Err(invalid(None, "chunk", "level has no chunks".into()));
+		}
+
+		if self.chunks.len() > u8::MAX as usize {
+			return Err(invalid(None, "chunk", format!("level has `{}` chunks but at most `{}` are allowed", self.chunks.len(), u8::MAX)));
+		}
+
+		if !self.chunks.iter().any(|chunk| chunk.is_spawnable) {
+			return Err(invalid(None, "chunk", "no chunk is spawnable".into()));
+		}
+
+		let mut total_width = 0.0;
+
+		for (i, chunk) in self.chunks.iter().enumerate() {
+			let section = || Some(format!("chunk[{i}]."));
+
+			if !(0.0..=1.0).contains(&chunk.width) {
+				return Err(invalid(section(), "width", format!("width `{}` is not in the range `0.0..=1.0`", chunk.width)));
+			}
+
+			total_width += chunk.width;
+
+			if !(0.0..=0.5).contains(&chunk.blend) {
+				return Err(invalid(section(), "blend", format!("blend `{}` is not in the range `0.0..=0.5`", chunk.blend)));
+			}
+
+			if let Some(ref noise) = chunk.noise {
+				validate_noise(noise).map_err(|(field, message)| invalid(Some(format!("chunk[{i}].noise.")), field, message))?;
+			}
+
+			let mut total_height = 0.0;
+
+			for (j, layer) in chunk.layers.iter().enumerate() {
+				let section = || Some(format!("chunk[{i}].layer[{j}]."));
+
+				if !(0.0..=1.0).contains(&layer.height) {
+					return Err(invalid(section(), "height", format!("height `{}` is not in the range `0.0..=1.0`", layer.height)));
+				}
+
+				total_height += layer.height;
+
+				if let Some(ref noise) = layer.noise {
+					validate_noise(noise).map_err(|(field, message)| invalid(Some(format!("chunk[{i}].layer[{j}].noise.")), field, message))?;
+				}
+			}
+
+			if total_height > 1.0 + SUM_TOLERANCE {
+				return Err(invalid(section(), "layer", format!("layer heights sum to `{total_height}` which is more than `1.0`")));
+			}
+		}
+
+		if total_width > 1.0 + SUM_TOLERANCE {
+			return Err(invalid(None, "chunk", format!("chunk widths sum to `{total_width}` which is more than `1.0`")));
+		}
+
+		Ok(())
+	}
+}
+
+fn validate_noise(noise: &Noise) -> std::result::Result<(), (&'static str, String)> {
+	if !(0.0..=1.0).contains(&noise.amplitude) {
+		return Err(("amplitude", format!("amplitude `{}` is not in the range `0.0..=1.0`", noise.amplitude)));
+	}
+
+	if !noise.frequency.is_finite() || noise.frequency < 0.0 {
+		return Err(("frequency", format!("frequency `{}` is not finite and non-negative", noise.frequency)));
+	}
+
+	if !(0x1..=Noise::MAX_OCTAVES).contains(&noise.octaves) {
+		return Err(("octaves", format!("octaves `{}` is not in the range `1..={}`", noise.octaves, Noise::MAX_OCTAVES)));
+	}
+
+	Ok(())
+}
@@ -0,0 +1,141 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::Level;
+use crate::log::log;
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs::{read_dir, read_to_string};
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+struct LevelInfoHelper {
+	pub level: LevelInfoLevelHelper,
+}
+
+#[derive(Debug, Deserialize)]
+struct LevelInfoLevelHelper {
+	pub name:        String,
+	pub creatour:    String,
+	pub description: String,
+}
+
+/// Information about an available level.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LevelInfo {
+	/// The identifier used for loading the level.
+	///
+	/// This is either the name of a built-in level or the file stem of a level file.
+	pub id: Box<str>,
+
+	pub name:        Box<str>,
+	pub creatour:    Box<str>,
+	pub description: Box<str>,
+
+	/// Denotes whether the level is a built-in.
+	///
+	/// Level files take precedence over built-ins with the same identifier.
+	pub is_builtin: bool,
+}
+
+impl LevelInfo {
+	/// Discovers all available levels.
+	///
+	/// This includes the built-in levels as well as every `*.toml` file in the given level directory.
+	/// Files that cannot be read are skipped with a warning.
+	/// Levels are sorted by their identifiers.
+	#[must_use]
+	pub fn discover(dir: &Path) -> Vec<Self> {
+		let mut levels = BTreeMap::<Box<str>, Self>::new();
+
+		for &id in Level::BUILTIN_NAMES {
+			let level = Level::load_builtin(id).expect("built-in level should exist");
+
+			let info = Self {
+				id: id.into(),
+
+				name:        level.name.into(),
+				creatour:    level.creatour.into(),
+				description: level.description.into(),
+
+				is_builtin: true,
+			};
+
+			levels.insert(id.into(), info);
+		}
+
+		log!(debug, "discovering levels in \"{}\"", dir.display());
+
+		let entries = match read_dir(dir) {
+			Ok(entries) => entries,
+
+			Err(e) => {
+				log!(warning, "unable to read level directory \"{}\": {e}", dir.display());
+
+				return levels.into_values().collect();
+			}
+		};
+
+		for entry in entries {
+			let Ok(entry) = entry else { continue };
+
+			let path = entry.path();
+
+			if path.extension().is_none_or(|extension| extension != "toml") {
+				continue;
+			}
+
+			let Some(id) = path.file_stem().and_then(|stem| stem.to_str()) else {
+				continue;
+			};
+
+			let helper = read_to_string(&path)
+				.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+				.and_then(|file| toml::from_str::<LevelInfoHelper>(&file).map_err(Into::into));
+
+			let helper = match helper {
+				Ok(helper) => helper,
+
+				Err(e) => {
+					log!(warning, "skipping level at \"{}\": {e}", path.display());
+					continue;
+				}
+			};
+
+			let info = Self {
+				id: id.into(),
+
+				name:        helper.level.name.into(),
+				creatour:    helper.level.creatour.into(),
+				description: helper.level.description.into(),
+
+				is_builtin: false,
+			};
+
+			if levels.get(id).is_some_and(|other| other.is_builtin) {
+				log!(note, "level file \"{}\" overrides built-in level", path.display());
+			}
+
+			levels.insert(id.into(), info);
+		}
+
+		levels.into_values().collect()
+	}
+
+	/// Finds levels that share their names with other levels.
+	///
+	/// Each returned group contains the identifiers of levels with the same name.
+	#[must_use]
+	pub fn find_duplicates(levels: &[Self]) -> Vec<Vec<&str>> {
+		let mut names = BTreeMap::<&str, Vec<&str>>::new();
+
+		for level in levels {
+			names.entry(&level.name).or_default().push(&level.id);
+		}
+
+		names
+			.into_values()
+			.filter(|ids| ids.len() > 0x1)
+			.collect()
+	}
+}
@@ -2,6 +2,7 @@
 
 use crate::level::MaterialFromStrError;
 
+use std::fmt::{self, Display, Formatter};
 use std::mem::transmute;
 use std::str::FromStr;
 
@@ -36,10 +37,61 @@ pub enum Material {
 }
 
 impl Material {
+	/// Converts a raw value into a material.
+	///
+	/// If the value does not denote any material, then this function returns [`None`].
+	#[inline]
+	#[must_use]
+	pub const fn new(value: u8) -> Option<Self> {
+		if value > Self::Fire as u8 {
+			return None;
+		}
+
+		// SAFETY: We have just tested that the value is
+		// in bounds.
+		let this = unsafe { Self::new_unchecked(value) };
+		Some(this)
+	}
+
 	pub const unsafe fn new_unchecked(value: u8) -> Self {
 		// SAFETY: Caller guarantees bounds.
 		unsafe { transmute::<u8, Self>(value) }
 	}
+
+	/// Gets the name of the material.
+	///
+	/// This is the same name as is accepted by [`from_str`](Self::from_str).
+	#[inline]
+	#[must_use]
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::Air       => "air",
+			Self::Basalt    => "basalt",
+			Self::Bedrock   => "bedrock",
+			Self::Clay      => "clay",
+			Self::Dirt      => "dirt",
+			Self::Fire      => "fire",
+			Self::Glass     => "glass",
+			Self::Granite   => "granite",
+			Self::Grass     => "grass",
+			Self::Gravel    => "gravel",
+			Self::Ice       => "ice",
+			Self::Limestone => "limestone",
+			Self::Magma     => "magma",
+			Self::Marble    => "marble",
+			Self::Sand      => "sand",
+			Self::Rock      => "rock",
+			Self::Water     => "water",
+			Self::Wood      => "wood",
+		}
+	}
+}
+
+impl Display for Material {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "{}", self.name())
+	}
 }
 
 impl FromStr for Material {
@@ -53,15 +105,19 @@ impl FromStr for Material {
 			"bedrock"   => Ok(Self::Bedrock),
 			"clay"      => Ok(Self::Clay),
 			"dirt"      => Ok(Self::Dirt),
+			"fire"      => Ok(Self::Fire),
+			"glass"     => Ok(Self::Glass),
 			"granite"   => Ok(Self::Granite),
 			"grass"     => Ok(Self::Grass),
 			"gravel"    => Ok(Self::Gravel),
+			"ice"       => Ok(Self::Ice),
 			"limestone" => Ok(Self::Limestone),
 			"magma"     => Ok(Self::Magma),
 			"marble"    => Ok(Self::Marble),
 			"sand"      => Ok(Self::Sand),
 			"rock"     => Ok(Self::Rock),
 			"water"     => Ok(Self::Water),
+			"wood"      => Ok(Self::Wood),
 
 			_ => Err(MaterialFromStrError { name: s.into() })
 		}
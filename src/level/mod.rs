@@ -1,19 +1,29 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
+mod bitmap;
 mod block;
 mod block_tags;
+mod block_tags_from_str_error;
+mod bundled_level;
 mod chunk;
 mod layer;
 mod level;
+mod level_info;
 mod material;
 mod material_from_str_error;
+mod noise;
 mod seed;
 
+pub use bitmap::Bitmap;
 pub use block::Block;
 pub use block_tags::BlockTags;
+pub use block_tags_from_str_error::BlockTagsFromStrError;
+pub use bundled_level::BundledLevel;
 pub use chunk::Chunk;
 pub use layer::Layer;
 pub use level::Level;
+pub use level_info::LevelInfo;
 pub use material::Material;
 pub use material_from_str_error::MaterialFromStrError;
+pub use noise::Noise;
 pub use seed::Seed;
@@ -0,0 +1,98 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+/// Procedural terrain noise.
+///
+/// Noise is used for perturbing the heights of chunks and layers per column.
+/// It is a sum of octaves of smoothed value noise, each octave having twice the frequency and half the amplitude of the previous one.
+///
+/// Noise is deterministic: The same seed and coordinate always yield the same value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Noise {
+	/// The maximum displacement, relative to the map height.
+	pub amplitude: f64,
+
+	/// The amount of periods across the map width.
+	pub frequency: f64,
+
+	/// The amount of summed octaves.
+	pub octaves: u8,
+}
+
+impl Noise {
+	/// The maximum amount of octaves.
+	pub const MAX_OCTAVES: u8 = 0x8;
+
+	/// Samples the noise.
+	///
+	/// The coordinate is relative to the map width.
+	/// The returned displacement is in the range `-amplitude..=amplitude`.
+	#[must_use]
+	pub fn sample(&self, seed: u64, x: f64) -> f64 {
+		let mut total     = 0.0;
+		let mut weight    = 1.0;
+		let mut weights   = 0.0;
+		let mut frequency = self.frequency;
+
+		for octave in 0x0..self.octaves {
+			let seed = mix(seed ^ u64::from(octave));
+
+			total    = weight.mul_add(sample_octave(seed, x * frequency), total);
+			weights += weight;
+
+			weight    *= 0.5;
+			frequency *= 2.0;
+		}
+
+		if weights <= 0.0 {
+			return 0.0;
+		}
+
+		total / weights * self.amplitude
+	}
+}
+
+/// Samples a single octave of value noise.
+///
+/// The result is in the range `-1.0..=1.0`.
+#[must_use]
+fn sample_octave(seed: u64, x: f64) -> f64 {
+	let x = x.max(0.0);
+
+	let cell = x.floor();
+	let t    = x - cell;
+
+	let cell = cell as u64;
+
+	let start = lattice(seed, cell);
+	let end   = lattice(seed, cell.wrapping_add(0x1));
+
+	// Interpolate smoothly between lattice points.
+
+	let t = t * t * t.mul_add(-2.0, 3.0);
+
+	(end - start).mul_add(t, start)
+}
+
+/// Gets the value at a lattice point.
+#[must_use]
+fn lattice(seed: u64, point: u64) -> f64 {
+	let value = mix(seed ^ mix(point));
+
+	// Use the upper 53 bits for full precision.
+
+	let value = (value >> 0xB) as f64 / (0x1u64 << 0x35) as f64;
+
+	value.mul_add(2.0, -1.0)
+}
+
+/// Mixes the bits of a value.
+///
+/// This is the finaliser of SplitMix64.
+#[must_use]
+const fn mix(mut value: u64) -> u64 {
+	value = value.wrapping_add(0x9E3779B97F4A7C15);
+	value = (value ^ (value >> 0x1E)).wrapping_mul(0xBF58476D1CE4E5B9);
+	value = (value ^ (value >> 0x1B)).wrapping_mul(0x94D049BB133111EB);
+
+	value ^ (value >> 0x1F)
+}
@@ -2,13 +2,13 @@
 
 macro_rules! log {
 	(debug, $($fmt:tt)+) => {{
-		if ::std::cfg!(debug_assertions) {
+		if ::bedrock::log::Verbosity::get() >= ::bedrock::log::Verbosity::Verbose {
 			::std::eprintln!($($fmt)*);
 		}
 	}};
 
 	(note, $($fmt:tt)+) => {{
-		if ::std::cfg!(debug_assertions) {
+		if ::bedrock::log::Verbosity::get() >= ::bedrock::log::Verbosity::Verbose {
 			::std::eprint!("\u{001B}[002mnote\u{001B}[039m: ");
 			::std::eprintln!($($fmt)*);
 			::std::eprint!("\u{001B}[039m\u{001B}[022m");
@@ -26,7 +26,9 @@ macro_rules! log {
 	}};
 
 	($($fmt:tt)+) => {{
-		::std::eprintln!($($fmt)*);
+		if ::bedrock::log::Verbosity::get() >= ::bedrock::log::Verbosity::Normal {
+			::std::eprintln!($($fmt)*);
+		}
 	}};
 }
 
@@ -1,5 +1,8 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
 mod log;
+mod verbosity;
 
 pub(crate) use log::log;
+
+pub use verbosity::Verbosity;
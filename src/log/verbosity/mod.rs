@@ -0,0 +1,57 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::DEFAULT as u8);
+
+/// A log verbosity.
+///
+/// Warnings and errors are always logged.
+/// The current verbosity is global and may be changed at any time.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Verbosity {
+	/// Only log warnings and errors.
+	Quiet = 0x0,
+
+	/// Also log general messages.
+	Normal = 0x1,
+
+	/// Also log notes and debug messages.
+	Verbose = 0x2,
+}
+
+impl Verbosity {
+	/// The default verbosity.
+	///
+	/// Debug builds are verbose by default.
+	pub const DEFAULT: Self = if cfg!(debug_assertions) {
+		Self::Verbose
+	} else {
+		Self::Normal
+	};
+
+	/// Gets the current verbosity.
+	#[inline]
+	#[must_use]
+	pub fn get() -> Self {
+		match VERBOSITY.load(Ordering::Relaxed) {
+			0x0 => Self::Quiet,
+			0x1 => Self::Normal,
+			_   => Self::Verbose,
+		}
+	}
+
+	/// Sets the current verbosity.
+	#[inline]
+	pub fn set(self) {
+		VERBOSITY.store(self as u8, Ordering::Relaxed);
+	}
+}
+
+impl Default for Verbosity {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::DEFAULT
+	}
+}
@@ -23,6 +23,9 @@ mod message;
 mod log;
 mod player;
 mod preset;
+mod projectile;
+mod rule;
+mod snapshot;
 mod version;
 
 #[cfg(not(target_env = "msvc"))]
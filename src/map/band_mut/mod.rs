@@ -0,0 +1,68 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::Block;
+use crate::map::{ColumnState, ColumnsMut, RowWindowsMut};
+
+/// A band of map columns.
+///
+/// Bands are used for ticking different parts of a map in parallel.
+/// A band owns a contiguous range of columns, but may additionally borrow the columns that follow it, in which case [`row_windows_mut`](Self::row_windows_mut) also includes the windows that cross into the first following column.
+#[must_use]
+#[derive(Debug)]
+pub struct BandMut<'a> {
+	width:  u32,
+	height: u32,
+
+	data:    &'a mut [Block],
+	columns: &'a mut [ColumnState],
+}
+
+impl<'a> BandMut<'a> {
+	#[inline(always)]
+	pub(super) fn new(
+		width:   u32,
+		height:  u32,
+		data:    &'a mut [Block],
+		columns: &'a mut [ColumnState],
+	) -> Self {
+		debug_assert!(data.len() % height as usize == 0x0);
+		debug_assert!(data.len() / height as usize == columns.len());
+		debug_assert!(columns.len() >= width as usize);
+
+		Self {
+			width,
+			height,
+
+			data,
+			columns,
+		}
+	}
+
+	/// Iterates over the columns owned by the band.
+	///
+	/// The states of the columns are returned alongside the iterator.
+	#[inline]
+	pub fn columns_mut(&mut self) -> (ColumnsMut, &mut [ColumnState]) {
+		let width = self.width as usize;
+		let len   = width * self.height as usize;
+
+		let columns = ColumnsMut::new(&mut self.data[..len], self.height);
+
+		(columns, &mut self.columns[..width])
+	}
+
+	/// Iterates over the horizontal windows of the band.
+	///
+	/// The last column of the map is never used as a left block.
+	/// The states of all borrowed columns are returned alongside the iterator.
+	#[inline]
+	pub fn row_windows_mut(&mut self) -> (RowWindowsMut, &mut [ColumnState]) {
+		let columns = self.columns.len() as u32;
+
+		let pairs = self.width.min(columns - 0x1);
+
+		let windows = RowWindowsMut::new(&mut *self.data, self.height, pairs);
+
+		(windows, &mut *self.columns)
+	}
+}
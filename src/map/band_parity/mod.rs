@@ -0,0 +1,10 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+/// The parity of a band index.
+///
+/// See [`Map::interleaved_bands_mut`](crate::map::Map::interleaved_bands_mut).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BandParity {
+	Even,
+	Odd,
+}
@@ -0,0 +1,40 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+/// The activity state of a map column.
+///
+/// Columns that have not changed for a while are put to sleep and are then only ticked occasionally.
+/// See [`Map::settle`](crate::map::Map::settle) for more information.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ColumnState {
+	/// The amount of ticks left until the column falls asleep.
+	pub(super) awake: u8,
+
+	/// Denotes whether the column is to be ticked.
+	pub(super) is_active: bool,
+
+	/// Denotes whether the column has changed during the current tick.
+	pub(super) is_changed: bool,
+}
+
+impl ColumnState {
+	/// The amount of ticks that a column is kept awake after it (or a neighbour) has changed.
+	pub const WAKE_TICKS: u8 = 0x40;
+
+	/// The interval at which sleeping columns are ticked anyway.
+	///
+	/// This lets slow reactions -- e.g. growth of grass -- continue in settled terrain, albeit at a reduced rate.
+	pub const PROBE_INTERVAL: u32 = 0x10;
+
+	/// Tests if the column is to be ticked.
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_active(self) -> bool {
+		self.is_active
+	}
+
+	/// Marks the column as changed.
+	#[inline(always)]
+	pub const fn mark_changed(&mut self) {
+		self.is_changed = true;
+	}
+}
@@ -1,7 +1,6 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
 use crate::level::Block;
-use crate::map::ColumnsMut;
 
 use std::marker::PhantomData;
 
@@ -16,22 +15,17 @@ pub struct ColumnWindowsMut<'a> {
 
 impl<'a> ColumnWindowsMut<'a> {
 	#[inline(always)]
-	#[must_use]
-	pub(super) fn new(columns: &mut ColumnsMut<'a>) -> Option<Self> {
-		let column = columns.next()?;
-
+	pub fn new(column: &'a mut [Block]) -> Self {
 		let len = column.len();
 		let ptr = column.as_mut_ptr();
 
 		debug_assert!(len % 0x2 == 0x0);
 
-		let this = Self {
+		Self {
 			len,
 			ptr,
 			_lifetime: PhantomData,
-		};
-
-		Some(this)
+		}
 	}
 
 	#[inline]
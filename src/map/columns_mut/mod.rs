@@ -1,7 +1,7 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
 use crate::level::Block;
-use crate::map::{ColumnWindowsMut, Map};
+use crate::map::ColumnWindowsMut;
 
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
@@ -15,16 +15,19 @@ pub struct ColumnsMut<'a> {
 	len: u32,
 	ptr: *mut Block,
 
-	_map: PhantomData<&'a mut Map>,
+	_data: PhantomData<&'a mut [Block]>,
 }
 
 impl<'a> ColumnsMut<'a> {
+	/// Constructs a new iterator over the columns of a map buffer.
+	///
+	/// The length of the buffer must be a multiple of `height`.
 	#[inline(always)]
-	pub(super) fn new(map: &'a mut Map) -> Self {
-		let height = map.height();
+	pub(super) fn new(data: &'a mut [Block], height: u32) -> Self {
+		debug_assert!(data.len() % height as usize == 0x0);
 
-		let len = map.width();
-		let ptr = map.as_mut_ptr();
+		let len = (data.len() / height as usize) as u32;
+		let ptr = data.as_mut_ptr();
 
 		Self {
 			height,
@@ -32,13 +35,14 @@ impl<'a> ColumnsMut<'a> {
 			len,
 			ptr,
 
-			_map: PhantomData,
+			_data: PhantomData,
 		}
 	}
 
+	#[expect(unused)]
 	#[inline(always)]
-	pub fn next_as_windows_mut(&mut self) -> Option<ColumnWindowsMut> {
-		ColumnWindowsMut::new(self)
+	pub fn next_as_windows_mut(&mut self) -> Option<ColumnWindowsMut<'a>> {
+		self.next().map(ColumnWindowsMut::new)
 	}
 }
 
@@ -60,7 +64,7 @@ impl<'a> Iterator for ColumnsMut<'a> {
 		// height. We have also in this case tested that
 		// there are remaining columns. We are also
 		// guaranteed to exclusively access the buffer
-		// thanks to `self._map`.
+		// thanks to `self._data`.
 		let data = unsafe { slice::from_raw_parts_mut(self.ptr, height) };
 
 		self.ptr = unsafe { self.ptr.add(height) };
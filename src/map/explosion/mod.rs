@@ -0,0 +1,62 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+/// An explosion.
+///
+/// Explosions carve circular craters into the map (see [`Map::explode`](crate::map::Map::explode)).
+/// Coordinates and distances are measured in blocks from the bottom-left corner of the map.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Explosion {
+	/// The centre of the explosion.
+	pub centre: (f64, f64),
+
+	/// The radius within which blocks are removed.
+	pub radius: f64,
+
+	/// The fraction of removed blocks that are scattered as debris.
+	pub debris: f64,
+
+	/// Denotes whether fire is spawned around the rim of the crater.
+	pub is_incendiary: bool,
+
+	/// The damage dealt to players at the centre.
+	///
+	/// This decreases linearly to zero at the radius.
+	pub damage: u32,
+}
+
+impl Explosion {
+	/// The explosion of an ordinary artillery shell.
+	pub const SHELL: Self = Self {
+		centre:        (0.0, 0.0),
+		radius:        6.0,
+		debris:        0.25,
+		is_incendiary: false,
+		damage:        0x32,
+	};
+
+	/// The width of the rim in which fire may be spawned.
+	pub const RIM_WIDTH: f64 = 1.5;
+
+	/// Tests if a point is within the explosion's radius.
+	#[inline]
+	#[must_use]
+	pub fn hits(&self, (x, y): (f64, f64)) -> bool {
+		self.distance((x, y)) <= self.radius
+	}
+
+	/// Gets the damage dealt at a point.
+	#[inline]
+	#[must_use]
+	pub fn damage_at(&self, (x, y): (f64, f64)) -> u32 {
+		let falloff = 1.0 - self.distance((x, y)) / self.radius;
+
+		(f64::from(self.damage) * falloff.clamp(0.0, 1.0)).round() as u32
+	}
+
+	/// Gets the distance from the explosion's centre to a point.
+	#[inline]
+	#[must_use]
+	pub fn distance(&self, (x, y): (f64, f64)) -> f64 {
+		(x - self.centre.0).hypot(y - self.centre.1)
+	}
+}
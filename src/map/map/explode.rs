@@ -0,0 +1,127 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::{Block, BlockTags, Material};
+use crate::map::{Explosion, Map};
+
+use rand::Rng;
+
+impl Map {
+	/// Detonates an explosion.
+	///
+	/// All blocks within the explosion's radius are removed, except for those tagged as [`DIVINE`](BlockTags::DIVINE).
+	/// A fraction of the removed blocks are scattered around the crater as debris of the same material, dropped onto the terrain from above.
+	/// If the explosion is incendiary, then fire is spawned in the empty blocks around the crater's rim.
+	///
+	/// The given targets, e.g. player positions, are tested against the explosion.
+	/// The indices of those that were hit are returned.
+	pub fn explode<R, I>(&mut self, explosion: &Explosion, targets: I, rng: &mut R) -> Vec<usize>
+	where
+		R: Rng,
+		I: IntoIterator<Item = (f64, f64)>,
+	{
+		let (width, height) = self.size().get();
+
+		let reach = explosion.radius + Explosion::RIM_WIDTH;
+
+		let (centre_x, centre_y) = explosion.centre;
+
+		let x_range = {
+			let start = (centre_x - reach).floor().max(0.0) as u32;
+			let end   = ((centre_x + reach).ceil().max(0.0) as u32).min(width);
+
+			start..end
+		};
+
+		let y_range = {
+			let start = (centre_y - reach).floor().max(0.0) as u32;
+			let end   = ((centre_y + reach).ceil().max(0.0) as u32).min(height);
+
+			start..end
+		};
+
+		let mut debris = Vec::new();
+
+		for x in x_range.clone() {
+			let mut is_changed = false;
+
+			for y in y_range.clone() {
+				// Measure from the centre of each block.
+
+				let distance = explosion.distance((f64::from(x) + 0.5, f64::from(y) + 0.5));
+
+				let index = x as usize * height as usize + y as usize;
+				let block = &mut self.data[index];
+
+				let tags = block.tags();
+
+				if distance <= explosion.radius {
+					if tags.intersects(BlockTags::EMPTY | BlockTags::DIVINE) {
+						continue;
+					}
+
+					if rng.random_bool(explosion.debris.clamp(0.0, 1.0)) {
+						debris.push(block.material());
+					}
+
+					block.set_material(Material::Air);
+					is_changed = true;
+				} else if explosion.is_incendiary && distance <= reach && tags.contains(BlockTags::EMPTY) && rng.random_bool(0.5) {
+					block.set_material(Material::Fire);
+					is_changed = true;
+				}
+			}
+
+			if is_changed {
+				self.mark_changed(x);
+			}
+		}
+
+		// Scatter the debris to either side of the crater.
+
+		let spread = 2.0 * explosion.radius;
+
+		for material in debris {
+			let offset = rng.random_range(-spread..=spread);
+
+			let x = centre_x + offset;
+
+			if x < 0.0 || x >= f64::from(width) {
+				continue;
+			}
+
+			let x = x as u32;
+
+			if let Some(y) = self.drop_height(x) {
+				let index = x as usize * height as usize + y as usize;
+
+				self.data[index].set_material(material);
+				self.mark_changed(x);
+			}
+		}
+
+		targets
+			.into_iter()
+			.enumerate()
+			.filter(|&(_, position)| explosion.hits(position))
+			.map(|(i, _)| i)
+			.collect()
+	}
+
+	/// Finds the lowest empty block in a column that is above all non-empty blocks.
+	///
+	/// If the column is full, then this method returns [`None`].
+	#[must_use]
+	fn drop_height(&self, x: u32) -> Option<u32> {
+		let height = self.height();
+
+		let start = x as usize * height as usize;
+		let column: &[Block] = &self.data[start..start + height as usize];
+
+		let top = column
+			.iter()
+			.rposition(|block| !block.tags().contains(BlockTags::EMPTY))
+			.map_or(0x0, |y| y + 0x1);
+
+		(top < column.len()).then_some(top as u32)
+	}
+}
@@ -1,16 +1,28 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
+mod explode;
+
 use crate::level::Block;
-use crate::map::{ColumnsMut, MapSize};
+use crate::map::{BandMut, BandParity, ColumnState, ColumnsMut, MapSize};
 
 use std::hint::assert_unchecked;
 use std::mem::swap;
 use std::num::NonZero;
+use std::ops::Range;
 
 #[derive(Clone, Debug)]
 pub struct Map {
 	height: u32,
 	data:   Box<[Block]>,
+
+	/// The activity states of each column.
+	columns: Box<[ColumnState]>,
+
+	/// The amount of times the map has been settled.
+	age: u32,
+
+	/// The range of columns that have changed since they were last taken.
+	dirty: Option<Range<u32>>,
 }
 
 impl Map {
@@ -22,7 +34,51 @@ impl Map {
 
 		let data = vec![Default::default(); data_len].into();
 
-		Self { height: size.height(), data }
+		let columns = vec![Default::default(); size.width() as usize].into();
+
+		let mut this = Self {
+			height: size.height(),
+			data,
+
+			columns,
+
+			age:   0x0,
+			dirty: None,
+		};
+
+		this.mark_all_changed();
+		this.settle();
+
+		this
+	}
+
+	/// Constructs a map from existing blocks.
+	///
+	/// The blocks must be laid out column by column, starting from the bottom of the leftmost column.
+	/// If the amount of blocks does not match the provided size, then this constructor returns [`None`].
+	#[inline]
+	#[must_use]
+	pub fn from_blocks(size: MapSize, data: Box<[Block]>) -> Option<Self> {
+		if data.len() != size.product() as usize {
+			return None;
+		}
+
+		let columns = vec![Default::default(); size.width() as usize].into();
+
+		let mut this = Self {
+			height: size.height(),
+			data,
+
+			columns,
+
+			age:   0x0,
+			dirty: None,
+		};
+
+		this.mark_all_changed();
+		this.settle();
+
+		Some(this)
 	}
 
 	#[inline]
@@ -41,6 +97,98 @@ impl Map {
 		let mut data = data.into();
 
 		swap(&mut data, &mut self.data);
+
+		self.height  = size.height();
+		self.columns = vec![Default::default(); size.width() as usize].into();
+
+		self.mark_all_changed();
+		self.settle();
+	}
+
+	/// Marks a column as changed.
+	///
+	/// The column and its neighbours are woken the next time the map is [settled](Self::settle).
+	#[inline]
+	#[track_caller]
+	pub fn mark_changed(&mut self, x: u32) {
+		self.columns[x as usize].mark_changed();
+	}
+
+	/// Marks all columns as changed.
+	#[inline]
+	pub fn mark_all_changed(&mut self) {
+		for column in &mut self.columns {
+			column.mark_changed();
+		}
+	}
+
+	/// Gets the columns that have changed since the map was last settled.
+	#[inline]
+	pub fn changed_columns(&self) -> impl Iterator<Item = u32> {
+		self
+			.columns
+			.iter()
+			.enumerate()
+			.filter(|&(_, column)| column.is_changed)
+			.map(|(x, _)| x as u32)
+	}
+
+	/// Tests if all columns have fallen asleep.
+	///
+	/// Sleeping columns are still probed occasionally, so the map may yet change.
+	#[inline]
+	#[must_use]
+	pub fn is_settled(&self) -> bool {
+		self.columns.iter().all(|column| column.awake == 0x0)
+	}
+
+	/// Settles the changes made during a tick.
+	///
+	/// Changed columns and their neighbours are kept awake for [`WAKE_TICKS`](ColumnState::WAKE_TICKS) ticks, after which they fall asleep.
+	/// Sleeping columns are only active every [`PROBE_INTERVAL`](ColumnState::PROBE_INTERVAL) ticks, at different offsets for each column.
+	pub fn settle(&mut self) {
+		self.age = self.age.wrapping_add(0x1);
+
+		let width = self.columns.len();
+
+		for column in &mut self.columns {
+			column.awake = column.awake.saturating_sub(0x1);
+		}
+
+		for x in 0x0..width {
+			if !self.columns[x].is_changed {
+				continue;
+			}
+
+			let start = x.saturating_sub(0x1);
+			let end   = (x + 0x2).min(width);
+
+			for column in &mut self.columns[start..end] {
+				column.awake = ColumnState::WAKE_TICKS;
+			}
+
+			let x = x as u32;
+
+			self.dirty = Some(match self.dirty {
+				Some(ref dirty) => dirty.start.min(x)..dirty.end.max(x + 0x1),
+
+				None => x..x + 0x1,
+			});
+		}
+
+		for (x, column) in self.columns.iter_mut().enumerate() {
+			let is_probed = self.age.wrapping_add(x as u32) % ColumnState::PROBE_INTERVAL == 0x0;
+
+			column.is_active  = column.awake != 0x0 || is_probed;
+			column.is_changed = false;
+		}
+	}
+
+	/// Takes the range of columns that have changed since the last call.
+	#[inline]
+	#[must_use]
+	pub fn take_dirty(&mut self) -> Option<Range<u32>> {
+		self.dirty.take()
 	}
 
 	#[inline(always)]
@@ -63,7 +211,64 @@ impl Map {
 
 	#[inline(always)]
 	pub fn columns_mut(&mut self) -> ColumnsMut {
-		ColumnsMut::new(self)
+		let height = self.height();
+
+		ColumnsMut::new(&mut self.data, height)
+	}
+
+	/// Splits the map into bands of columns.
+	///
+	/// Each band owns `band_width` columns, except for the last which may own fewer.
+	/// The bands do not borrow any columns besides their own.
+	#[must_use]
+	pub fn bands_mut(&mut self, band_width: u32) -> Vec<BandMut> {
+		assert!(band_width != 0x0);
+
+		let height   = self.height();
+		let band_len = band_width as usize * height as usize;
+
+		self.data
+			.chunks_mut(band_len)
+			.zip(self.columns.chunks_mut(band_width as usize))
+			.map(|(data, columns)| {
+				let width = columns.len() as u32;
+
+				BandMut::new(width, height, data, columns)
+			})
+			.collect()
+	}
+
+	/// Splits the map into every other band of columns.
+	///
+	/// Bands are laid out as with [`bands_mut`](Self::bands_mut), but only those of the given parity are yielded.
+	/// Each of these additionally borrows the following band, so that blocks at the band's right edge may interact with their neighbours.
+	#[must_use]
+	pub fn interleaved_bands_mut(&mut self, band_width: u32, parity: BandParity) -> Vec<BandMut> {
+		assert!(band_width != 0x0);
+
+		let height   = self.height();
+		let band_len = band_width as usize * height as usize;
+
+		let (data, columns) = match parity {
+			BandParity::Even => (&mut *self.data, &mut *self.columns),
+
+			BandParity::Odd => {
+				let data_mid    = band_len.min(self.data.len());
+				let columns_mid = (band_width as usize).min(self.columns.len());
+
+				(&mut self.data[data_mid..], &mut self.columns[columns_mid..])
+			}
+		};
+
+		data
+			.chunks_mut(band_len * 0x2)
+			.zip(columns.chunks_mut(band_width as usize * 0x2))
+			.map(|(data, columns)| {
+				let width = columns.len().min(band_width as usize) as u32;
+
+				BandMut::new(width, height, data, columns)
+			})
+			.collect()
 	}
 
 	#[inline(always)]
@@ -102,6 +307,18 @@ impl Map {
 		unsafe { MapSize::new_unchecked(width, height) }
 	}
 
+	#[inline(always)]
+	#[must_use]
+	pub fn as_slice(&self) -> &[Block] {
+		&self.data
+	}
+
+	#[inline(always)]
+	#[must_use]
+	pub fn as_mut_slice(&mut self) -> &mut [Block] {
+		&mut self.data
+	}
+
 	#[expect(unused)]
 	#[inline(always)]
 	#[must_use]
@@ -109,6 +326,7 @@ impl Map {
 		self.data.as_ptr()
 	}
 
+	#[expect(unused)]
 	#[inline(always)]
 	#[must_use]
 	pub fn as_mut_ptr(&mut self) -> *mut Block {
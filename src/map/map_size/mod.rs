@@ -14,6 +14,11 @@ pub struct MapSize {
 }
 
 impl MapSize {
+	/// The largest amount of blocks in a map.
+	///
+	/// This bounds the memory used by maps, e.g. when loading them from untrusted snapshots.
+	pub const MAX_PRODUCT: u32 = 0x0100_0000;
+
 	#[inline(always)]
 	#[must_use]
 	pub const fn new(width: u32, height: u32) -> Option<Self> {
@@ -22,15 +27,17 @@ impl MapSize {
 
 		// FIXME(const-hacks): We cannot try in constant
 		// expressions.
-		if height.checked_mul(width).is_none() {
-			return None;
+		match height.checked_mul(width) {
+			Some(product) if product <= Self::MAX_PRODUCT => { }
+
+			_ => return None,
 		}
 
 		// SAFETY: We have tested that the two axes:
 		//
 		// * Are even,
 		// * Are non-zero, and
-		// * Produce a non-overflowing product.
+		// * Produce a product no greater than `MAX_PRODUCT`.
 		let this = unsafe { Self::new_unchecked(width, height) };
 		Some(this)
 	}
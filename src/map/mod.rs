@@ -1,11 +1,23 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
+mod band_mut;
+mod band_parity;
+mod column_state;
 mod column_windows_mut;
 mod columns_mut;
+mod explosion;
 mod map;
 mod map_size;
+mod row_windows_mut;
+mod sticky_clusters;
 
+pub use band_mut::BandMut;
+pub use band_parity::BandParity;
+pub use column_state::ColumnState;
 pub use column_windows_mut::ColumnWindowsMut;
 pub use columns_mut::ColumnsMut;
+pub use explosion::Explosion;
 pub use map::Map;
 pub use map_size::MapSize;
+pub use row_windows_mut::RowWindowsMut;
+pub use sticky_clusters::StickyClusters;
@@ -0,0 +1,99 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+#[cfg(test)]
+mod test;
+
+use crate::level::Block;
+
+use std::marker::PhantomData;
+
+/// Horizontal windows over a map.
+///
+/// Each window consists of a block and its right neighbour.
+/// Windows are yielded row by row, starting from the bottom-left corner of the map.
+#[must_use]
+#[derive(Debug)]
+pub struct RowWindowsMut<'a> {
+	pairs:  u32,
+	height: u32,
+
+	x: u32,
+	y: u32,
+
+	ptr: *mut Block,
+
+	_data: PhantomData<&'a mut [Block]>,
+}
+
+impl<'a> RowWindowsMut<'a> {
+	/// Constructs a new iterator over the rows of a map buffer.
+	///
+	/// Only the first `pairs` columns are used as left blocks.
+	/// The buffer must contain at least one column more than this.
+	#[inline(always)]
+	pub(super) fn new(data: &'a mut [Block], height: u32, pairs: u32) -> Self {
+		debug_assert!(data.len() % height as usize == 0x0);
+		debug_assert!(data.len() / height as usize > pairs as usize);
+
+		let ptr = data.as_mut_ptr();
+
+		Self {
+			pairs,
+			height,
+
+			x: 0x0,
+			y: 0x0,
+
+			ptr,
+
+			_data: PhantomData,
+		}
+	}
+
+	#[inline]
+	#[must_use]
+	pub fn next(&mut self) -> Option<[&'a mut Block; 0x2]> {
+		if self.pairs == 0x0 || self.y >= self.height {
+			return None;
+		}
+
+		let height = self.height as usize;
+
+		let index = self.x as usize * height + self.y as usize;
+
+		// SAFETY: `x` is always less than `pairs` and `y`
+		// is always less than the height. The buffer con-
+		// tains at least one column more than `pairs`, so
+		// both blocks are within bounds.
+		let block      = unsafe { &mut *self.ptr.add(index) };
+		let next_block = unsafe { &mut *self.ptr.add(index + height) };
+
+		self.x += 0x1;
+
+		if self.x == self.pairs {
+			self.x  = 0x0;
+			self.y += 0x1;
+		}
+
+		Some([block, next_block])
+	}
+
+	/// Skips the next window.
+	///
+	/// The next window is only skipped if it is in the same row as the previous one, so that skips do not carry over from the end of one row to the start of the next.
+	#[inline]
+	pub fn skip(&mut self) {
+		if self.x == 0x0 {
+			return;
+		}
+
+		let _ = self.next();
+	}
+
+	/// Retrieves the column of the next window's left block.
+	#[inline(always)]
+	#[must_use]
+	pub fn x(&self) -> u32 {
+		self.x
+	}
+}
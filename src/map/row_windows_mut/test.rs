@@ -0,0 +1,57 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::{Block, Material};
+use crate::map::RowWindowsMut;
+
+/// The materials of a map with three columns and two rows.
+///
+/// Each block has a distinct material, so that windows can be told apart.
+const MATERIALS: [Material; 0x6] = [
+	Material::Rock,
+	Material::Dirt,
+	Material::Sand,
+	Material::Clay,
+	Material::Gravel,
+	Material::Marble,
+];
+
+fn blocks() -> Vec<Block> {
+	MATERIALS
+		.iter()
+		.map(|&material| {
+			let mut block = Block::default();
+			block.set_material(material);
+
+			block
+		})
+		.collect()
+}
+
+#[test]
+fn skip_stays_within_row() {
+	let mut data = blocks();
+
+	let mut windows = RowWindowsMut::new(&mut data, 0x2, 0x2);
+
+	let [block, _] = windows.next().unwrap();
+	assert_eq!(block.material(), Material::Rock);
+
+	let [block, next_block] = windows.next().unwrap();
+	assert_eq!(block.material(),      Material::Sand);
+	assert_eq!(next_block.material(), Material::Gravel);
+
+	// Skipping after the last window of a row must not
+	// skip the first window of the next row.
+
+	windows.skip();
+
+	let [block, next_block] = windows.next().unwrap();
+	assert_eq!(block.material(),      Material::Dirt);
+	assert_eq!(next_block.material(), Material::Clay);
+
+	// Skipping within a row does skip the next window.
+
+	windows.skip();
+
+	assert!(windows.next().is_none());
+}
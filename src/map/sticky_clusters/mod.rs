@@ -0,0 +1,205 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::Block;
+use crate::map::Map;
+
+/// A cluster of connected sticky blocks.
+#[derive(Clone, Copy, Debug)]
+struct Cluster {
+	/// The offset of the cluster's first member.
+	start: usize,
+
+	/// The amount of members in the cluster.
+	len: usize,
+
+	/// Denotes whether the cluster touches a solid, static block.
+	is_supported: bool,
+
+	/// Denotes whether all blocks below the cluster are empty.
+	can_fall: bool,
+}
+
+/// Tracker of connected sticky blocks.
+///
+/// Sticky blocks of the same material that are physically connected form a *cluster*.
+/// Clusters that touch any solid, [static](crate::level::BlockTags::STATIC) block are supported and are kept in place.
+/// Other clusters fall as a whole -- one block per update -- for as long as there is room below them.
+///
+/// Only clusters that touch columns that have changed -- or that neighbour such columns -- are relabelled, as the support of other clusters cannot have changed.
+/// Labels are numbered anew each update, so that stale labels need not be cleared, and the buffers are kept between updates so that updating does not allocate once warmed up.
+///
+/// This must be updated after all changes of a tick and before the map is [settled](Map::settle).
+/// Replacing the map requires a new tracker.
+#[derive(Clone, Debug, Default)]
+pub struct StickyClusters {
+	labels:   Vec<u32>,
+	members:  Vec<usize>,
+	clusters: Vec<Cluster>,
+
+	stack:   Vec<usize>,
+	columns: Vec<u32>,
+
+	/// The columns in which clusters fell during the previous update.
+	fallen: Vec<u32>,
+
+	/// The first label of the current update.
+	///
+	/// Lower labels are left over from earlier updates.
+	first_label: u32,
+}
+
+impl StickyClusters {
+	const NO_LABEL: u32 = 0x0;
+
+	/// Updates the clusters of the map and lets unsupported ones fall.
+	pub fn update(&mut self, map: &mut Map) {
+		let width  = map.width();
+		let height = map.height() as usize;
+
+		let len = width as usize * height;
+
+		self.columns.clear();
+
+		if self.labels.len() == len {
+			// Clusters may lose their support to a neigh-
+			// bouring column, so include these as well.
+
+			for x in map.changed_columns().chain(self.fallen.drain(..)) {
+				let start = x.saturating_sub(0x1);
+				let end   = (x + 0x2).min(width);
+
+				self.columns.extend(start..end);
+			}
+
+			self.columns.sort_unstable();
+			self.columns.dedup();
+		} else {
+			// The map is new, so label everything.
+
+			self.labels.clear();
+			self.labels.resize(len, Self::NO_LABEL);
+
+			self.first_label = Self::NO_LABEL + 0x1;
+
+			self.fallen.clear();
+			self.columns.extend(0x0..width);
+		}
+
+		if self.columns.is_empty() {
+			return;
+		}
+
+		// Each update uses at most one label per block.
+		// Restart the numbering before it overflows.
+
+		if self.first_label.checked_add(len as u32).is_none() {
+			self.labels.fill(Self::NO_LABEL);
+
+			self.first_label = Self::NO_LABEL + 0x1;
+		}
+
+		self.members.clear();
+		self.clusters.clear();
+
+		let blocks = map.as_mut_slice();
+
+		for i in 0x0..self.columns.len() {
+			let x = self.columns[i] as usize;
+
+			for index in x * height..(x + 0x1) * height {
+				if !blocks[index].is_sticky() || self.labels[index] >= self.first_label {
+					continue;
+				}
+
+				let cluster = self.label_cluster(blocks, height, index);
+				self.clusters.push(cluster);
+			}
+		}
+
+		self.first_label += self.clusters.len() as u32;
+
+		for cluster in &self.clusters {
+			if cluster.is_supported || !cluster.can_fall {
+				continue;
+			}
+
+			let members = &mut self.members[cluster.start..cluster.start + cluster.len];
+
+			// Move members from the bottom and up, so that
+			// each member falls into the space that the mem-
+			// ber below it has just left.
+
+			members.sort_unstable();
+
+			for &index in &*members {
+				blocks.swap(index - 0x1, index);
+
+				// Remember the column for later.
+				self.fallen.push((index / height) as u32);
+			}
+		}
+
+		self.fallen.sort_unstable();
+		self.fallen.dedup();
+
+		for &x in &self.fallen {
+			map.mark_changed(x);
+		}
+	}
+
+	fn label_cluster(&mut self, blocks: &[Block], height: usize, origin: usize) -> Cluster {
+		let label = self.first_label + u32::try_from(self.clusters.len()).unwrap();
+
+		let material = blocks[origin].material();
+
+		let mut cluster = Cluster {
+			start: self.members.len(),
+			len:   0x0,
+
+			is_supported: false,
+			can_fall:     true,
+		};
+
+		self.labels[origin] = label;
+		self.stack.push(origin);
+
+		while let Some(index) = self.stack.pop() {
+			self.members.push(index);
+			cluster.len += 0x1;
+
+			let y = index % height;
+
+			if y == 0x0 {
+				cluster.can_fall = false;
+			}
+
+			let below = (y != 0x0).then(|| index - 0x1);
+			let above = (y != height - 0x1).then(|| index + 0x1);
+			let left  = (index >= height).then(|| index - height);
+			let right = (index + height < blocks.len()).then(|| index + height);
+
+			for neighbour in [below, above, left, right].into_iter().flatten() {
+				let block = blocks[neighbour];
+
+				if block.is_sticky() && block.material() == material {
+					if self.labels[neighbour] < self.first_label {
+						self.labels[neighbour] = label;
+						self.stack.push(neighbour);
+					}
+
+					continue;
+				}
+
+				if block.is_static() && !block.is_emtpy() {
+					cluster.is_supported = true;
+				}
+
+				if Some(neighbour) == below && !block.is_emtpy() {
+					cluster.can_fall = false;
+				}
+			}
+		}
+
+		cluster
+	}
+}
@@ -1,13 +1,17 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
 mod connexion;
+mod movement;
 mod player;
 mod player_list;
 mod team;
 mod token;
+mod walk;
 
 pub use connexion::Connexion;
+pub use movement::Movement;
 pub use player::Player;
 pub use player_list::PlayerList;
 pub use team::Team;
 pub use token::Token;
+pub use walk::Walk;
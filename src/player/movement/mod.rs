@@ -0,0 +1,52 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+/// The parameters of player movement.
+///
+/// Distances are measured in blocks and durations in ticks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Movement {
+	/// The distance walked per tick.
+	pub walk_speed: f64,
+
+	/// The highest step that can be climbed whilst walking, in whole blocks.
+	pub step_height: u32,
+
+	/// The downwards acceleration.
+	pub gravity: f64,
+
+	/// The terminal falling speed.
+	pub max_fall_speed: f64,
+
+	/// The factor by which speeds are multiplied whilst in liquids.
+	pub liquid_drag: f64,
+
+	/// The highest fall that does not hurt.
+	pub safe_fall: f64,
+
+	/// The damage dealt per block fallen beyond [`safe_fall`](Self::safe_fall).
+	pub fall_damage: f64,
+
+	/// The damage dealt per tick whilst touching hot blocks.
+	pub heat_damage: u32,
+}
+
+impl Movement {
+	/// Movement for ordinary tokens.
+	pub const NORMAL: Self = Self {
+		walk_speed:     0.25,
+		step_height:    0x1,
+		gravity:        0.0625,
+		max_fall_speed: 2.0,
+		liquid_drag:    0.5,
+		safe_fall:      4.0,
+		fall_damage:    4.0,
+		heat_damage:    0x2,
+	};
+}
+
+impl Default for Movement {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::NORMAL
+	}
+}
@@ -1,6 +1,8 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
-use crate::player::{Connexion, Team, Token};
+mod step;
+
+use crate::player::{Connexion, Team, Token, Walk};
 
 use std::borrow::Cow;
 
@@ -12,5 +14,32 @@ pub struct Player {
 
 	pub is_admin: bool,
 
+	/// The position of the player's feet, in blocks from the bottom-left corner of the map.
+	pub position: (f64, f64),
+
+	/// Denotes whether the player has been placed on the map.
+	pub is_spawned: bool,
+
+	pub health: u32 = Self::MAX_HEALTH,
+
+	pub walk: Walk,
+
+	/// The current falling speed, in blocks per tick.
+	pub fall_speed: f64,
+
+	/// The height at which the current fall started, if falling.
+	pub fall_start: Option<f64>,
+
 	pub connexion: Connexion,
 }
+
+impl Player {
+	/// The health of unhurt players.
+	pub const MAX_HEALTH: u32 = 0x64;
+
+	#[inline(always)]
+	#[must_use]
+	pub const fn is_alive(&self) -> bool {
+		self.health != 0x0
+	}
+}
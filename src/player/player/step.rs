@@ -0,0 +1,172 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::BlockTags;
+use crate::map::Map;
+use crate::player::{Player, Walk};
+
+impl Player {
+	/// The height of players, in blocks.
+	pub const HEIGHT: f64 = 2.0;
+
+	/// The maximum distance fallen per sub-step.
+	///
+	/// This is less than a block, so that players cannot fall through terrain that is a single block thick.
+	pub const MAX_FALL_STEP: f64 = 0.5;
+
+	/// Tests whether the player is spawned and stuck inside terrain.
+	#[inline]
+	#[must_use]
+	pub fn is_buried(&self, map: &Map) -> bool {
+		self.is_spawned && !is_free(map, self.position.0, self.position.1)
+	}
+
+	/// Steps the player by a single tick.
+	///
+	/// The player firstly walks in the direction of [`walk`](Self::walk), climbing steps if blocked, and then falls under gravity.
+	/// Movement is resolved against the blocks of the map and uses the parameters of the player's token (see [`Token::movement`](crate::player::Token::movement)).
+	///
+	/// Blocks tagged as [`EMPTY`](BlockTags::EMPTY) or [`LIQUID`](BlockTags::LIQUID) can be moved through, with liquids slowing the player and breaking falls.
+	/// Falls higher than the safe height and touching blocks tagged as [`HOT`](BlockTags::HOT) hurt the player.
+	///
+	/// The damage taken is subtracted from the player's health and returned.
+	pub fn step(&mut self, map: &Map) -> u32 {
+		if !self.is_spawned || !self.is_alive() {
+			return 0x0;
+		}
+
+		let movement = self.token.movement();
+
+		// Players that have been buried by falling blocks
+		// climb out a block at a time.
+
+		if !is_free(map, self.position.0, self.position.1) {
+			self.position.1 = self.position.1.floor() + 1.0;
+
+			self.fall_speed = 0.0;
+			self.fall_start = None;
+
+			return 0x0;
+		}
+
+		let is_submerged = is_in_liquid(map, self.position.0, self.position.1);
+
+		let drag = if is_submerged { movement.liquid_drag } else { 1.0 };
+
+		if self.walk != Walk::Still {
+			let x = self.walk.sign().mul_add(movement.walk_speed * drag, self.position.0);
+			let y = self.position.1;
+
+			if is_free(map, x, y) {
+				self.position.0 = x;
+			} else {
+				// Climb the lowest step that has room for the
+				// player, both above it and above the player.
+
+				let step = (0x1..=movement.step_height)
+					.map(|height| y.floor() + f64::from(height))
+					.find(|&y| is_free(map, x, y) && is_free(map, self.position.0, y));
+
+				if let Some(y) = step {
+					self.position = (x, y);
+				}
+			}
+		}
+
+		let mut damage = 0x0;
+
+		// Liquids break falls.
+
+		if is_submerged {
+			self.fall_start = None;
+		}
+
+		let fall_start = *self.fall_start.get_or_insert(self.position.1);
+
+		self.fall_speed = ((self.fall_speed + movement.gravity) * drag).min(movement.max_fall_speed);
+
+		let mut remaining = self.fall_speed;
+		let mut has_landed = false;
+
+		while remaining > 0.0 {
+			let step = remaining.min(Self::MAX_FALL_STEP);
+			remaining -= step;
+
+			let y = self.position.1 - step;
+
+			if is_solid(map, self.position.0, y) {
+				self.position.1 = y.floor() + 1.0;
+
+				has_landed = true;
+				break;
+			}
+
+			self.position.1 = y;
+		}
+
+		if has_landed {
+			let height = fall_start - self.position.1;
+
+			if height > movement.safe_fall {
+				damage += ((height - movement.safe_fall) * movement.fall_damage) as u32;
+			}
+
+			self.fall_speed = 0.0;
+			self.fall_start = None;
+		}
+
+		if is_touching_heat(map, self.position.0, self.position.1) {
+			damage += movement.heat_damage;
+		}
+
+		self.health = self.health.saturating_sub(damage);
+
+		damage
+	}
+}
+
+/// Tests whether the block at the given position blocks players.
+///
+/// The edges of the map are solid, whilst everything above the map is not.
+#[must_use]
+fn is_solid(map: &Map, x: f64, y: f64) -> bool {
+	if y >= f64::from(map.height()) {
+		return false;
+	}
+
+	map
+		.sample(x, y)
+		.is_none_or(|block| !block.tags().intersects(BlockTags::EMPTY.union(BlockTags::LIQUID)))
+}
+
+/// Tests whether a player with their feet at the given position would fit.
+#[must_use]
+fn is_free(map: &Map, x: f64, y: f64) -> bool {
+	body_rows(y).all(|y| !is_solid(map, x, y))
+}
+
+/// Tests whether a player with their feet at the given position is in a liquid.
+#[must_use]
+fn is_in_liquid(map: &Map, x: f64, y: f64) -> bool {
+	map
+		.sample(x, y)
+		.is_some_and(|block| block.tags().contains(BlockTags::LIQUID))
+}
+
+/// Tests whether a player with their feet at the given position touches a hot block.
+///
+/// This includes the block that the player is standing on.
+#[must_use]
+fn is_touching_heat(map: &Map, x: f64, y: f64) -> bool {
+	body_rows(y)
+		.chain([y - 0.5])
+		.filter_map(|y| map.sample(x, y))
+		.any(|block| block.tags().contains(BlockTags::HOT))
+}
+
+/// Gets the centres of the rows that are covered by a player with their feet at the given height.
+fn body_rows(y: f64) -> impl Iterator<Item = f64> {
+	let bottom = y.floor() as u32;
+	let top    = (y + Player::HEIGHT).ceil() as u32;
+
+	(bottom..top).map(|y| f64::from(y) + 0.5)
+}
@@ -2,9 +2,11 @@
 
 use oct::decode::Decode;
 use oct::encode::{Encode, SizedEncode};
+use serde::{Deserialize, Serialize};
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug, Decode, Default, Encode, Eq, PartialEq, SizedEncode)]
+#[derive(Clone, Copy, Debug, Decode, Default, Deserialize, Encode, Eq, PartialEq, Serialize, SizedEncode)]
+#[serde(rename_all = "snake_case")]
 pub enum Team {
 	#[default]
 	None,
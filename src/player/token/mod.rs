@@ -1,10 +1,14 @@
 // Copyright 2025 Gabriel Bjørnager Jensen.
 
+use crate::player::Movement;
+
 use oct::decode::Decode;
 use oct::encode::{Encode, SizedEncode};
+use serde::{Deserialize, Serialize};
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug, Decode, Default, Encode, Eq, PartialEq, SizedEncode)]
+#[derive(Clone, Copy, Debug, Decode, Default, Deserialize, Encode, Eq, PartialEq, Serialize, SizedEncode)]
+#[serde(rename_all = "snake_case")]
 pub enum Token {
 	#[default]
 	Pawn,
@@ -18,3 +22,71 @@ pub enum Token {
 	Duck,
 	Wastebasket,
 }
+
+impl Token {
+	/// Gets the movement parameters of the token.
+	#[must_use]
+	pub const fn movement(self) -> Movement {
+		match self {
+			Self::Pawn => Movement::NORMAL,
+
+			Self::Bicycle => Movement {
+				walk_speed:  0.5,
+				step_height: 0x0,
+
+				..Movement::NORMAL
+			},
+
+			Self::Train => Movement {
+				walk_speed:  0.375,
+				step_height: 0x0,
+				heat_damage: 0x1,
+
+				..Movement::NORMAL
+			},
+
+			Self::Boot => Movement {
+				step_height: 0x3,
+
+				..Movement::NORMAL
+			},
+
+			Self::Dog => Movement {
+				walk_speed:  0.375,
+				step_height: 0x2,
+
+				..Movement::NORMAL
+			},
+
+			Self::Tank => Movement {
+				walk_speed:  0.125,
+				step_height: 0x2,
+				fall_damage: 2.0,
+				heat_damage: 0x1,
+
+				..Movement::NORMAL
+			},
+
+			Self::Cannon => Movement {
+				walk_speed:  0.125,
+				fall_damage: 2.0,
+
+				..Movement::NORMAL
+			},
+
+			Self::Duck => Movement {
+				liquid_drag: 1.0,
+				safe_fall:   8.0,
+
+				..Movement::NORMAL
+			},
+
+			Self::Wastebasket => Movement {
+				walk_speed:  0.1875,
+				heat_damage: 0x4,
+
+				..Movement::NORMAL
+			},
+		}
+	}
+}
@@ -0,0 +1,26 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+/// The direction in which a player is walking.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Walk {
+	#[default]
+	Still,
+
+	Left,
+	Right,
+}
+
+impl Walk {
+	/// Gets the horizontal sign of the direction.
+	///
+	/// This is `-1.0` for left, `1.0` for right, and `0.0` when standing still.
+	#[inline]
+	#[must_use]
+	pub const fn sign(self) -> f64 {
+		match self {
+			Self::Still => 0.0,
+			Self::Left  => -1.0,
+			Self::Right => 1.0,
+		}
+	}
+}
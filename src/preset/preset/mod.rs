@@ -5,14 +5,22 @@ use crate::map::MapSize;
 
 use oct::decode::Decode;
 use oct::encode::{Encode, SizedEncode};
+use rand::random;
 
 #[derive(Clone, Debug, Decode, Encode, SizedEncode)]
 #[oct(decode_error = DecodeError)]
 pub struct Preset {
 	pub map_size: MapSize,
 	pub tps:      u16,
+	pub seed:     u64,
 
 	pub friendly_fire: bool,
+
+	/// The time limit of each turn, in seconds.
+	pub turn_time: u16,
+
+	/// The amount of rounds in a match.
+	pub round_count: u8,
 }
 
 // FIXME: `syn` does not parse default field val-
@@ -23,8 +31,12 @@ impl Default for Preset {
 		Self {
 			map_size: Default::default(),
 			tps:      0x8,
+			seed:     random(),
 
 			friendly_fire: true,
+
+			turn_time:   0x1E,
+			round_count: 0x3,
 		}
 	}
 }
@@ -0,0 +1,32 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+/// The environment in which projectiles fly.
+///
+/// All quantities are measured in blocks and ticks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ballistics {
+	/// The downwards acceleration.
+	pub gravity: f64,
+
+	/// The horizontal acceleration, positive being rightwards.
+	pub wind: f64,
+
+	/// The factor by which velocities are multiplied when passing through liquids.
+	pub liquid_drag: f64,
+}
+
+impl Ballistics {
+	/// Ballistics without wind.
+	pub const CALM: Self = Self {
+		gravity:     0.0625,
+		wind:        0.0,
+		liquid_drag: 0.5,
+	};
+}
+
+impl Default for Ballistics {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::CALM
+	}
+}
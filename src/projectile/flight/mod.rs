@@ -0,0 +1,14 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+/// The outcome of a projectile step.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Flight {
+	/// The projectile is still flying.
+	Airborne,
+
+	/// The projectile has collided with the block at the given coordinates.
+	Collided { x: u32, y: u32 },
+
+	/// The projectile has left the map through its sides or bottom.
+	Escaped,
+}
@@ -0,0 +1,9 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+mod ballistics;
+mod flight;
+mod projectile;
+
+pub use ballistics::Ballistics;
+pub use flight::Flight;
+pub use projectile::Projectile;
@@ -0,0 +1,98 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+#[cfg(test)]
+mod test;
+
+use crate::level::BlockTags;
+use crate::map::Map;
+use crate::projectile::{Ballistics, Flight};
+
+/// A projectile, e.g. an artillery shell.
+///
+/// Positions are measured in blocks from the bottom-left corner of the map, and velocities in blocks per tick.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Projectile {
+	pub position: (f64, f64),
+	pub velocity: (f64, f64),
+}
+
+impl Projectile {
+	/// The maximum distance travelled per sub-step.
+	///
+	/// This is less than a block, so that projectiles cannot tunnel through terrain that is a single block thick.
+	pub const MAX_STEP: f64 = 0.5;
+
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(position: (f64, f64), velocity: (f64, f64)) -> Self {
+		Self { position, velocity }
+	}
+
+	/// Steps the projectile by a single tick.
+	///
+	/// The velocity is firstly accelerated by gravity and wind, after which the projectile is moved in sub-steps of at most [`MAX_STEP`](Self::MAX_STEP) blocks.
+	/// Blocks tagged as [`EMPTY`](BlockTags::EMPTY) are passed through, and blocks tagged as [`LIQUID`](BlockTags::LIQUID) slow the projectile.
+	/// Any other block stops the projectile.
+	///
+	/// Projectiles above the map keep flying, as they may still fall back down.
+	pub fn step(&mut self, map: &Map, ballistics: &Ballistics) -> Flight {
+		self.velocity.0 += ballistics.wind;
+		self.velocity.1 -= ballistics.gravity;
+
+		let distance = self.velocity.0.hypot(self.velocity.1);
+
+		let step_count = (distance / Self::MAX_STEP).ceil().max(1.0) as u32;
+
+		let mut is_slowed = false;
+
+		for _ in 0x0..step_count {
+			// Recompute the step, as the velocity may have
+			// been slowed by liquids.
+
+			let step = (
+				self.velocity.0 / f64::from(step_count),
+				self.velocity.1 / f64::from(step_count),
+			);
+
+			self.position.0 += step.0;
+			self.position.1 += step.1;
+
+			let (x, y) = self.position;
+
+			if x < 0.0 || x >= f64::from(map.width()) || y < 0.0 {
+				return Flight::Escaped;
+			}
+
+			if y >= f64::from(map.height()) {
+				continue;
+			}
+
+			let Some(block) = map.sample(x, y) else {
+				continue;
+			};
+
+			let tags = block.tags();
+
+			if tags.contains(BlockTags::EMPTY) {
+				continue;
+			}
+
+			if tags.contains(BlockTags::LIQUID) {
+				// Only slow the projectile once per tick.
+
+				if !is_slowed {
+					is_slowed = true;
+
+					self.velocity.0 *= ballistics.liquid_drag;
+					self.velocity.1 *= ballistics.liquid_drag;
+				}
+
+				continue;
+			}
+
+			return Flight::Collided { x: x as u32, y: y as u32 };
+		}
+
+		Flight::Airborne
+	}
+}
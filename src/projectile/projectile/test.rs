@@ -0,0 +1,60 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::Material;
+use crate::map::{Map, MapSize};
+use crate::projectile::{Ballistics, Flight, Projectile};
+
+/// Creates a map of air with a wall that is a single block thick.
+fn walled_map(wall: u32) -> Map {
+	let size = MapSize::new(0x40, 0x20).unwrap();
+
+	let mut map = Map::new(size);
+
+	let height = map.height() as usize;
+	let start  = wall as usize * height;
+
+	for block in &mut map.as_mut_slice()[start..start + height] {
+		block.set_material(Material::Rock);
+	}
+
+	map
+}
+
+/// Steps a projectile until it stops flying.
+fn fly(map: &Map, ballistics: &Ballistics, mut projectile: Projectile) -> (Projectile, Flight) {
+	for _ in 0x0..0x100 {
+		let flight = projectile.step(map, ballistics);
+
+		if flight != Flight::Airborne {
+			return (projectile, flight);
+		}
+	}
+
+	panic!("projectile never landed");
+}
+
+#[test]
+fn fast_shell_hits_thin_wall() {
+	const WALL: u32 = 0x20;
+
+	let map = walled_map(WALL);
+
+	let ballistics = Ballistics {
+		gravity: 0.0,
+
+		..Ballistics::CALM
+	};
+
+	// Move several blocks per tick, so that the wall
+	// would be skipped without sub-steps.
+
+	for speed in [1.5, 3.0, 7.25, 16.0] {
+		let projectile = Projectile::new((4.5, 16.5), (speed, 0.0));
+
+		let (projectile, flight) = fly(&map, &ballistics, projectile);
+
+		assert_eq!(flight, Flight::Collided { x: WALL, y: 0x10 }, "shell at speed `{speed}` tunnelled");
+
+		assert!(projectile.position.0 < f64::from(WALL + 0x1));
+	}
+}
@@ -0,0 +1,21 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::Material;
+
+/// A rule action.
+///
+/// Actions are performed in order when a [rule](crate::rule::Rule) is applied.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+	/// Swap the two blocks.
+	Swap,
+
+	/// Skip the next window and stop applying rules to the current one.
+	Skip,
+
+	/// Change the material of the left-hand block.
+	TransmuteLhs(Material),
+
+	/// Change the material of the right-hand block.
+	TransmuteRhs(Material),
+}
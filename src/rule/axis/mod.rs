@@ -0,0 +1,29 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use serde::Deserialize;
+
+/// The axis along which a rule is applied.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Axis {
+	/// The rule is applied to a block and the block above it.
+	Vertical,
+
+	/// The rule is applied to a block and the block to the right of it.
+	Horizontal,
+
+	/// The rule is applied along both axes.
+	Any,
+}
+
+impl Axis {
+	/// Tests if the axis includes another.
+	#[inline]
+	#[must_use]
+	pub const fn includes(self, other: Self) -> bool {
+		matches!(
+			(self, other),
+			(Self::Any, _) | (Self::Vertical, Self::Vertical) | (Self::Horizontal, Self::Horizontal),
+		)
+	}
+}
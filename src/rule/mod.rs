@@ -0,0 +1,13 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+mod action;
+mod axis;
+mod predicate;
+mod rule;
+mod rule_set;
+
+pub use action::Action;
+pub use axis::Axis;
+pub use predicate::Predicate;
+pub use rule::Rule;
+pub use rule_set::RuleSet;
@@ -0,0 +1,31 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::{Block, BlockTags, Material};
+
+/// A rule predicate.
+///
+/// A block satisfies a predicate if it is of the given material (if any), has all of the given tags, and has none of the excluded tags.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Predicate {
+	pub material: Option<Material>,
+
+	pub tags:    BlockTags,
+	pub without: BlockTags,
+}
+
+impl Predicate {
+	/// Tests if a block satisfies the predicate.
+	#[inline]
+	#[must_use]
+	pub fn test(self, block: Block) -> bool {
+		if let Some(material) = self.material
+			&& material != block.material()
+		{
+			return false;
+		}
+
+		let tags = block.tags();
+
+		tags.contains(self.tags) && !tags.intersects(self.without)
+	}
+}
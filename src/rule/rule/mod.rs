@@ -0,0 +1,79 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::Block;
+use crate::rule::{Action, Axis, Predicate};
+
+use std::mem::swap;
+
+/// A reaction rule.
+///
+/// Rules are applied to pairs of neighbouring blocks -- the *left-hand* and the *right-hand* block -- along an [axis](Axis).
+/// If both blocks satisfy their respective [predicates](Predicate), then the rule's [actions](Action) are performed with the rule's chance.
+#[derive(Clone, Debug)]
+pub struct Rule {
+	pub axis: Axis,
+
+	pub lhs: Predicate,
+	pub rhs: Predicate,
+
+	/// Denotes whether the rule may also be applied with the two blocks switched.
+	pub is_symmetric: bool,
+
+	/// The minimum seed for which the rule is applied.
+	pub min_seed: u32,
+
+	pub actions: Box<[Action]>,
+}
+
+impl Rule {
+	/// Gets the minimum seed for the given chance.
+	///
+	/// The chance is given as a fraction, which must not be greater than one.
+	#[must_use]
+	pub const fn min_seed(chance_num: u32, chance_den: u32) -> Option<u32> {
+		if chance_den == 0x0 || chance_num > chance_den {
+			return None;
+		}
+
+		let num = u32::MAX as u64 * chance_num as u64;
+		let den = chance_den as u64;
+
+		let min_seed = u32::MAX - (num / den) as u32;
+		Some(min_seed)
+	}
+
+	/// Applies the rule to two blocks.
+	///
+	/// If the rule was applied, then the returned value denotes whether the next window should be skipped.
+	/// Otherwise -- i.e. if the blocks did not match or the chance was not met -- [`None`] is returned.
+	#[inline]
+	pub fn apply(&self, lhs: &mut Block, rhs: &mut Block, seed: u32) -> Option<bool> {
+		if seed < self.min_seed {
+			return None;
+		}
+
+		let (lhs, rhs) = if self.lhs.test(*lhs) && self.rhs.test(*rhs) {
+			(lhs, rhs)
+		} else if self.is_symmetric && self.lhs.test(*rhs) && self.rhs.test(*lhs) {
+			(rhs, lhs)
+		} else {
+			return None;
+		};
+
+		let mut skip = false;
+
+		for &action in &self.actions {
+			match action {
+				Action::Swap => swap(lhs, rhs),
+
+				Action::Skip => skip = true,
+
+				Action::TransmuteLhs(material) => lhs.set_material(material),
+
+				Action::TransmuteRhs(material) => rhs.set_material(material),
+			}
+		}
+
+		Some(skip)
+	}
+}
@@ -0,0 +1,217 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+#[cfg(test)]
+mod test;
+
+use crate::level::{Block, BlockTags, Material};
+use crate::rule::{Action, Axis, Predicate, Rule};
+
+use serde::Deserialize;
+use std::error::Error;
+
+#[derive(Debug, Deserialize)]
+struct RuleSetHelper {
+	#[serde(default)]
+	pub rule: Vec<RuleHelper>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RuleHelper {
+	pub axis: Axis,
+
+	pub lhs: PredicateHelper,
+	pub rhs: PredicateHelper,
+
+	#[serde(default)]
+	pub is_symmetric: bool,
+
+	pub chance: (u32, u32),
+
+	pub action: Vec<ActionHelper>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PredicateHelper {
+	#[serde(default)]
+	pub material: Option<String>,
+
+	#[serde(default)]
+	pub tags: Vec<String>,
+
+	#[serde(default)]
+	pub without: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ActionHelper {
+	Swap,
+	Skip,
+	TransmuteLhs(String),
+	TransmuteRhs(String),
+}
+
+/// A set of reaction rules.
+///
+/// Rules are applied in the order that they are defined in.
+/// See [`Rule`] for more information.
+#[derive(Clone, Debug)]
+pub struct RuleSet {
+	vertical:   Box<[Rule]>,
+	horizontal: Box<[Rule]>,
+}
+
+impl RuleSet {
+	/// The source of the built-in rules.
+	pub const BUILTIN: &'static str = include_str!("default.toml");
+
+	/// Parses a set of rules from TOML.
+	pub fn parse(source: &str) -> Result<Self, Box<dyn Error>> {
+		let helper = toml::from_str::<RuleSetHelper>(source)?;
+
+		let rules = helper
+			.rule
+			.into_iter()
+			.map(parse_rule)
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let this = Self::new(rules);
+		Ok(this)
+	}
+
+	/// Constructs a new set from a list of rules.
+	#[must_use]
+	pub fn new<I: IntoIterator<Item = Rule>>(rules: I) -> Self {
+		let mut vertical   = Vec::new();
+		let mut horizontal = Vec::new();
+
+		for rule in rules {
+			if rule.axis.includes(Axis::Vertical) {
+				vertical.push(rule.clone());
+			}
+
+			if rule.axis.includes(Axis::Horizontal) {
+				horizontal.push(rule);
+			}
+		}
+
+		Self {
+			vertical:   vertical.into(),
+			horizontal: horizontal.into(),
+		}
+	}
+
+	/// Applies all rules along the given axis to two blocks.
+	///
+	/// Each rule rolls its chance independently, so that an earlier rule does not prevent a later one from ever being applied.
+	/// Only the first rule to be applied -- i.e. whose blocks match and whose chance is met -- has any effect, so later rules never see blocks changed by an earlier one.
+	///
+	/// The returned value denotes whether the next window should be skipped.
+	#[inline]
+	pub fn apply(&self, axis: Axis, lhs: &mut Block, rhs: &mut Block, seed: u32) -> bool {
+		let rules = match axis {
+			Axis::Vertical   => &*self.vertical,
+			Axis::Horizontal => &*self.horizontal,
+
+			Axis::Any => unreachable!(),
+		};
+
+		rules
+			.iter()
+			.enumerate()
+			.find_map(|(index, rule)| rule.apply(lhs, rhs, rule_seed(seed, index)))
+			.unwrap_or(false)
+	}
+}
+
+impl Default for RuleSet {
+	#[inline]
+	fn default() -> Self {
+		Self::parse(Self::BUILTIN).expect("built-in rules should be valid")
+	}
+}
+
+/// Derives the seed of a single rule from the seed of a window.
+///
+/// This uses the finaliser of MurmurHash3, which mixes all bits of its input.
+#[inline(always)]
+#[must_use]
+const fn rule_seed(seed: u32, index: usize) -> u32 {
+	let mut value = seed ^ (index as u32).wrapping_mul(0x9E37_79B9);
+
+	value ^= value >> 0x10;
+	value = value.wrapping_mul(0x85EB_CA6B);
+	value ^= value >> 0xD;
+	value = value.wrapping_mul(0xC2B2_AE35);
+	value ^= value >> 0x10;
+
+	value
+}
+
+fn parse_rule(helper: RuleHelper) -> Result<Rule, Box<dyn Error>> {
+	let (chance_num, chance_den) = helper.chance;
+
+	let min_seed = Rule::min_seed(chance_num, chance_den)
+		.ok_or_else(|| format!("invalid chance `{chance_num}/{chance_den}`"))?;
+
+	let actions = helper
+		.action
+		.into_iter()
+		.map(parse_action)
+		.collect::<Result<_, _>>()?;
+
+	let rule = Rule {
+		axis: helper.axis,
+
+		lhs: parse_predicate(helper.lhs)?,
+		rhs: parse_predicate(helper.rhs)?,
+
+		is_symmetric: helper.is_symmetric,
+
+		min_seed,
+
+		actions,
+	};
+
+	Ok(rule)
+}
+
+fn parse_predicate(helper: PredicateHelper) -> Result<Predicate, Box<dyn Error>> {
+	let parse_tags = |tags: Vec<String>| -> Result<BlockTags, Box<dyn Error>> {
+		let mut result = BlockTags::NONE;
+
+		for tag in tags {
+			result |= tag.parse::<BlockTags>()?;
+		}
+
+		Ok(result)
+	};
+
+	let material = helper
+		.material
+		.map(|material| material.parse::<Material>())
+		.transpose()?;
+
+	let predicate = Predicate {
+		material,
+
+		tags:    parse_tags(helper.tags)?,
+		without: parse_tags(helper.without)?,
+	};
+
+	Ok(predicate)
+}
+
+fn parse_action(helper: ActionHelper) -> Result<Action, Box<dyn Error>> {
+	let action = match helper {
+		ActionHelper::Swap => Action::Swap,
+
+		ActionHelper::Skip => Action::Skip,
+
+		ActionHelper::TransmuteLhs(material) => Action::TransmuteLhs(material.parse()?),
+
+		ActionHelper::TransmuteRhs(material) => Action::TransmuteRhs(material.parse()?),
+	};
+
+	Ok(action)
+}
@@ -0,0 +1,66 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::{Block, Material};
+use crate::rule::{Axis, RuleSet};
+
+/// Applies the built-in rules to a pair of blocks with many different seeds.
+///
+/// The resulting pairs of materials are returned.
+fn react(axis: Axis, lhs: Material, rhs: Material) -> Vec<(Material, Material)> {
+	let rules = RuleSet::default();
+
+	(0x0..0x1_0000u32)
+		.map(|i| {
+			let mut lhs_block = Block::default();
+			let mut rhs_block = Block::default();
+
+			lhs_block.set_material(lhs);
+			rhs_block.set_material(rhs);
+
+			let seed = i.wrapping_mul(0x9E37_79B9);
+
+			let _ = rules.apply(axis, &mut lhs_block, &mut rhs_block, seed);
+
+			(lhs_block.material(), rhs_block.material())
+		})
+		.collect()
+}
+
+#[test]
+fn magma_and_water() {
+	for axis in [Axis::Vertical, Axis::Horizontal] {
+		for (lhs, rhs) in [(Material::Magma, Material::Water), (Material::Water, Material::Magma)] {
+			let outcomes = react(axis, lhs, rhs);
+
+			let solidified = outcomes.iter().any(|&pair| pair == (Material::Basalt, rhs) || pair == (lhs, Material::Basalt));
+			let evaporated = outcomes.iter().any(|&pair| pair == (Material::Air, rhs) || pair == (lhs, Material::Air));
+
+			assert!(solidified, "magma never solidified next to water ({axis:?})");
+			assert!(evaporated, "water never evaporated next to magma ({axis:?})");
+		}
+	}
+}
+
+#[test]
+fn ice_and_magma() {
+	for axis in [Axis::Vertical, Axis::Horizontal] {
+		for (lhs, rhs) in [(Material::Ice, Material::Magma), (Material::Magma, Material::Ice)] {
+			let outcomes = react(axis, lhs, rhs);
+
+			let melted = outcomes.iter().any(|&pair| pair == (Material::Water, rhs) || pair == (lhs, Material::Water));
+
+			assert!(melted, "ice never melted next to magma ({axis:?})");
+		}
+	}
+}
+
+#[test]
+fn ice_and_fire() {
+	for axis in [Axis::Vertical, Axis::Horizontal] {
+		let outcomes = react(axis, Material::Ice, Material::Fire);
+
+		let melted = outcomes.iter().any(|&(lhs, _)| lhs == Material::Water);
+
+		assert!(melted, "ice never melted next to fire ({axis:?})");
+	}
+}
@@ -0,0 +1,5 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+mod snapshot;
+
+pub use snapshot::Snapshot;
@@ -0,0 +1,339 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::error::DecodeError;
+use crate::level::{Bitmap, Block, Chunk, Layer, Level, Material, Noise};
+use crate::map::{Map, MapSize};
+use crate::preset::Preset;
+use crate::snapshot::Snapshot;
+use crate::version::Version;
+
+use polywave::www::Html;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+type Result<T> = std::result::Result<T, DecodeError>;
+
+impl Snapshot {
+	/// Decodes a snapshot.
+	///
+	/// Snapshots are only accepted if they use the same [format](Self::FORMAT) and were written by the same major and minor version of Bedrock.
+	pub fn decode(data: &[u8]) -> Result<Self> {
+		let mut input = Input { data };
+
+		if input.take(Self::MAGIC.len())? != Self::MAGIC {
+			return Err(DecodeError::new("not a snapshot"));
+		}
+
+		let format = input.take_u32()?;
+
+		if format != Self::FORMAT {
+			return Err(DecodeError::new(format_args!("snapshot has format `{format}` but `{}` is required", Self::FORMAT)));
+		}
+
+		let version = decode_version(&mut input)?;
+
+		if version.major != Version::CURRENT.major || version.minor != Version::CURRENT.minor {
+			return Err(DecodeError::new(format_args!("snapshot was written by incompatible version {version}")));
+		}
+
+		let preset = decode_preset(&mut input)?;
+		let level  = decode_level(&mut input)?;
+		let map    = decode_map(&mut input)?;
+		let rng    = decode_rng(&mut input)?;
+
+		if !input.data.is_empty() {
+			return Err(DecodeError::new("trailing data after snapshot"));
+		}
+
+		if preset.map_size.get() != map.size().get() {
+			return Err(DecodeError::new("map size does not match preset"));
+		}
+
+		let this = Self { preset, level, map, rng };
+		Ok(this)
+	}
+}
+
+/// A decoding cursor.
+struct Input<'a> {
+	data: &'a [u8],
+}
+
+impl<'a> Input<'a> {
+	fn take(&mut self, count: usize) -> Result<&'a [u8]> {
+		let Some((head, tail)) = self.data.split_at_checked(count) else {
+			return Err(DecodeError::new("unexpected end of snapshot"));
+		};
+
+		self.data = tail;
+
+		Ok(head)
+	}
+
+	fn take_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+		let data = self.take(N)?;
+
+		// NOTE: This always succeeds as `take` returns
+		// exactly `N` bytes.
+		Ok(data.try_into().unwrap())
+	}
+
+	#[inline]
+	fn take_u8(&mut self) -> Result<u8> {
+		self.take_array().map(u8::from_le_bytes)
+	}
+
+	#[inline]
+	fn take_u16(&mut self) -> Result<u16> {
+		self.take_array().map(u16::from_le_bytes)
+	}
+
+	#[inline]
+	fn take_u32(&mut self) -> Result<u32> {
+		self.take_array().map(u32::from_le_bytes)
+	}
+
+	#[inline]
+	fn take_u64(&mut self) -> Result<u64> {
+		self.take_array().map(u64::from_le_bytes)
+	}
+
+	#[inline]
+	fn take_u128(&mut self) -> Result<u128> {
+		self.take_array().map(u128::from_le_bytes)
+	}
+
+	#[inline]
+	fn take_f64(&mut self) -> Result<f64> {
+		self.take_array().map(f64::from_le_bytes)
+	}
+
+	fn take_bool(&mut self) -> Result<bool> {
+		match self.take_u8()? {
+			0x0 => Ok(false),
+			0x1 => Ok(true),
+
+			value => Err(DecodeError::new(format_args!("invalid boolean `{value:#04X}`"))),
+		}
+	}
+
+	fn take_str(&mut self) -> Result<String> {
+		let len = self.take_u32()? as usize;
+
+		let data = self.take(len)?;
+
+		str::from_utf8(data)
+			.map(Into::into)
+			.map_err(|_| DecodeError::new("invalid utf-8 in string"))
+	}
+
+	fn take_varint(&mut self) -> Result<u32> {
+		let mut value = 0x0u32;
+
+		for shift in (0x0..u32::BITS).step_by(0x7) {
+			let byte = self.take_u8()?;
+
+			value |= u32::from(byte & 0b01111111) << shift;
+
+			if byte & 0b10000000 == 0x0 {
+				return Ok(value);
+			}
+		}
+
+		Err(DecodeError::new("varint is too long"))
+	}
+}
+
+fn decode_version(input: &mut Input) -> Result<Version> {
+	let major = input.take_u32()?;
+	let minor = input.take_u32()?;
+	let patch = input.take_u32()?;
+
+	let has_pre = input.take_bool()?;
+	let pre     = input.take_u32()?;
+
+	let pre = has_pre.then_some(pre);
+
+	let version = Version { major, minor, patch, pre };
+	Ok(version)
+}
+
+fn decode_preset(input: &mut Input) -> Result<Preset> {
+	let map_size = decode_map_size(input)?;
+
+	let tps  = input.take_u16()?;
+	let seed = input.take_u64()?;
+
+	let friendly_fire = input.take_bool()?;
+
+	let turn_time   = input.take_u16()?;
+	let round_count = input.take_u8()?;
+
+	let preset = Preset {
+		map_size,
+		tps,
+		seed,
+
+		friendly_fire,
+
+		turn_time,
+		round_count,
+	};
+
+	Ok(preset)
+}
+
+fn decode_level(input: &mut Input) -> Result<Level> {
+	let name        = input.take_str()?;
+	let creatour    = input.take_str()?;
+	let description = input.take_str()?;
+
+	let background = Html::from_u32(u32::from_be_bytes(input.take_array()?));
+
+	let has_rules = input.take_bool()?;
+	let rules     = input.take_str()?;
+
+	let rules = has_rules.then_some(rules.into());
+
+	let chunk_count = input.take_u32()?;
+
+	let mut chunks = Vec::new();
+
+	for _ in 0x0..chunk_count {
+		let width        = input.take_f64()?;
+		let is_spawnable = input.take_bool()?;
+		let blend        = input.take_f64()?;
+		let noise        = decode_noise(input)?;
+
+		let layer_count = input.take_u32()?;
+
+		let mut layers = Vec::new();
+
+		for _ in 0x0..layer_count {
+			let height = input.take_f64()?;
+
+			let material = input.take_u8()?;
+
+			let material = Material::new(material)
+				.ok_or_else(|| DecodeError::new(format_args!("invalid material `{material:#04X}`")))?;
+
+			let noise = decode_noise(input)?;
+
+			layers.push(Layer { height, material, noise });
+		}
+
+		chunks.push(Chunk {
+			width,
+			is_spawnable,
+			blend,
+			noise,
+
+			layers: layers.into(),
+		});
+	}
+
+	let bitmap = if input.take_bool()? {
+		let width  = input.take_u32()?;
+		let height = input.take_u32()?;
+
+		let len = (width as usize)
+			.checked_mul(height as usize)
+			.ok_or_else(|| DecodeError::new("bitmap is too large"))?;
+
+		let materials = input
+			.take(len)?
+			.iter()
+			.map(|&material| {
+				Material::new(material)
+					.ok_or_else(|| DecodeError::new(format_args!("invalid material `{material:#04X}`")))
+			})
+			.collect::<Result<_>>()?;
+
+		let bitmap = Bitmap::new(width, height, materials)
+			.ok_or_else(|| DecodeError::new(format_args!("invalid bitmap size {width}*{height}")))?;
+
+		Some(bitmap)
+	} else {
+		None
+	};
+
+	let level = Level {
+		name:        name.into(),
+		creatour:    creatour.into(),
+		description: description.into(),
+
+		background,
+
+		rules,
+
+		chunks: chunks.into(),
+
+		bitmap,
+	};
+
+	Ok(level)
+}
+
+fn decode_noise(input: &mut Input) -> Result<Option<Noise>> {
+	if !input.take_bool()? {
+		return Ok(None);
+	}
+
+	let amplitude = input.take_f64()?;
+	let frequency = input.take_f64()?;
+	let octaves   = input.take_u8()?;
+
+	let noise = Noise { amplitude, frequency, octaves };
+	Ok(Some(noise))
+}
+
+fn decode_map(input: &mut Input) -> Result<Map> {
+	let size = decode_map_size(input)?;
+
+	let len = size.product() as usize;
+
+	// The size is bounded by `MapSize::MAX_PRODUCT`,
+	// but only grow the buffer as runs are decoded so
+	// that truncated snapshots fail early.
+
+	let mut data = Vec::new();
+
+	while data.len() < len {
+		let run = input.take_varint()? as usize;
+
+		let block = input.take_u8()?;
+
+		let block = Block::from_u8(block)
+			.ok_or_else(|| DecodeError::new(format_args!("invalid block `{block:#04X}`")))?;
+
+		if run == 0x0 || run > len - data.len() {
+			return Err(DecodeError::new("invalid run length in map"));
+		}
+
+		data.resize(data.len() + run, block);
+	}
+
+	// NOTE: The length has been checked above.
+	let map = Map::from_blocks(size, data.into()).unwrap();
+	Ok(map)
+}
+
+fn decode_rng(input: &mut Input) -> Result<ChaCha8Rng> {
+	let seed     = input.take_array()?;
+	let stream   = input.take_u64()?;
+	let word_pos = input.take_u128()?;
+
+	let mut rng = ChaCha8Rng::from_seed(seed);
+	rng.set_stream(stream);
+	rng.set_word_pos(word_pos);
+
+	Ok(rng)
+}
+
+fn decode_map_size(input: &mut Input) -> Result<MapSize> {
+	let width  = input.take_u32()?;
+	let height = input.take_u32()?;
+
+	MapSize::new(width, height)
+		.ok_or_else(|| DecodeError::new(format_args!("invalid map size {width}*{height}")))
+}
@@ -0,0 +1,174 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::{Level, Noise};
+use crate::map::Map;
+use crate::preset::Preset;
+use crate::snapshot::Snapshot;
+use crate::version::Version;
+
+use rand_chacha::ChaCha8Rng;
+
+impl Snapshot {
+	/// Encodes the snapshot.
+	#[must_use]
+	pub fn encode(&self) -> Vec<u8> {
+		let mut buf = Vec::new();
+
+		buf.extend_from_slice(&Self::MAGIC);
+
+		encode_u32(&mut buf, Self::FORMAT);
+		encode_version(&mut buf, Version::CURRENT);
+		encode_preset(&mut buf, &self.preset);
+		encode_level(&mut buf, &self.level);
+		encode_map(&mut buf, &self.map);
+		encode_rng(&mut buf, &self.rng);
+
+		buf
+	}
+}
+
+fn encode_version(buf: &mut Vec<u8>, version: Version) {
+	encode_u32(buf, version.major);
+	encode_u32(buf, version.minor);
+	encode_u32(buf, version.patch);
+
+	encode_bool(buf, version.pre.is_some());
+	encode_u32(buf, version.pre.unwrap_or_default());
+}
+
+fn encode_preset(buf: &mut Vec<u8>, preset: &Preset) {
+	let (width, height) = preset.map_size.get();
+
+	encode_u32(buf, width);
+	encode_u32(buf, height);
+
+	buf.extend_from_slice(&preset.tps.to_le_bytes());
+	buf.extend_from_slice(&preset.seed.to_le_bytes());
+
+	encode_bool(buf, preset.friendly_fire);
+
+	buf.extend_from_slice(&preset.turn_time.to_le_bytes());
+	buf.push(preset.round_count);
+}
+
+fn encode_level(buf: &mut Vec<u8>, level: &Level) {
+	encode_str(buf, &level.name);
+	encode_str(buf, &level.creatour);
+	encode_str(buf, &level.description);
+
+	// Colours are laid out as RGBA.
+	buf.extend_from_slice(&level.background.to_u32().to_be_bytes());
+
+	encode_bool(buf, level.rules.is_some());
+	encode_str(buf, level.rules.as_deref().unwrap_or_default());
+
+	encode_u32(buf, level.chunks.len() as u32);
+
+	for chunk in &*level.chunks {
+		encode_f64(buf, chunk.width);
+		encode_bool(buf, chunk.is_spawnable);
+		encode_f64(buf, chunk.blend);
+		encode_noise(buf, chunk.noise);
+
+		encode_u32(buf, chunk.layers.len() as u32);
+
+		for layer in &*chunk.layers {
+			encode_f64(buf, layer.height);
+			buf.push(layer.material as u8);
+			encode_noise(buf, layer.noise);
+		}
+	}
+
+	encode_bool(buf, level.bitmap.is_some());
+
+	if let Some(ref bitmap) = level.bitmap {
+		encode_u32(buf, bitmap.width());
+		encode_u32(buf, bitmap.height());
+
+		for y in 0x0..bitmap.height() {
+			for x in 0x0..bitmap.width() {
+				let material = bitmap.get(x, y).unwrap_or_default();
+
+				buf.push(material as u8);
+			}
+		}
+	}
+}
+
+fn encode_noise(buf: &mut Vec<u8>, noise: Option<Noise>) {
+	encode_bool(buf, noise.is_some());
+
+	if let Some(noise) = noise {
+		encode_f64(buf, noise.amplitude);
+		encode_f64(buf, noise.frequency);
+		buf.push(noise.octaves);
+	}
+}
+
+fn encode_map(buf: &mut Vec<u8>, map: &Map) {
+	let (width, height) = map.size().get();
+
+	encode_u32(buf, width);
+	encode_u32(buf, height);
+
+	// Encode each run as its length followed by the
+	// raw block.
+
+	let mut blocks = map.as_slice().iter().copied().peekable();
+
+	while let Some(block) = blocks.next() {
+		let mut len = 0x1u32;
+
+		while blocks.next_if_eq(&block).is_some() {
+			len += 0x1;
+		}
+
+		encode_varint(buf, len);
+		buf.push(block.to_u8());
+	}
+}
+
+#[inline]
+fn encode_rng(buf: &mut Vec<u8>, rng: &ChaCha8Rng) {
+	buf.extend_from_slice(&rng.get_seed());
+	buf.extend_from_slice(&rng.get_stream().to_le_bytes());
+	buf.extend_from_slice(&rng.get_word_pos().to_le_bytes());
+}
+
+fn encode_bool(buf: &mut Vec<u8>, value: bool) {
+	buf.push(u8::from(value));
+}
+
+#[inline]
+fn encode_u32(buf: &mut Vec<u8>, value: u32) {
+	buf.extend_from_slice(&value.to_le_bytes());
+}
+
+#[inline]
+fn encode_f64(buf: &mut Vec<u8>, value: f64) {
+	buf.extend_from_slice(&value.to_le_bytes());
+}
+
+#[inline]
+fn encode_str(buf: &mut Vec<u8>, value: &str) {
+	encode_u32(buf, value.len() as u32);
+	buf.extend_from_slice(value.as_bytes());
+}
+
+/// Encodes a variable-length integer.
+///
+/// Each byte contains seven bits of the value, starting with the least significant, and the most significant bit denotes whether more bytes follow.
+fn encode_varint(buf: &mut Vec<u8>, mut value: u32) {
+	loop {
+		let byte = (value & 0b01111111) as u8;
+
+		value >>= 0x7;
+
+		if value == 0x0 {
+			buf.push(byte);
+			break;
+		}
+
+		buf.push(byte | 0b10000000);
+	}
+}
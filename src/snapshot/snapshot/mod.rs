@@ -0,0 +1,46 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+#[cfg(test)]
+mod test;
+
+mod decode;
+mod encode;
+
+use crate::level::Level;
+use crate::map::Map;
+use crate::preset::Preset;
+
+use rand_chacha::ChaCha8Rng;
+
+/// A snapshot of a game.
+///
+/// Snapshots contain the map along with the level and preset that it was generated from, as well as the state of the game generator, and can be saved to and loaded from a compact binary format.
+///
+/// The format consists of a header -- a magic number followed by the [format version](Self::FORMAT) and the [version](crate::version::Version) of Bedrock that wrote it -- followed by the preset, the level, the map, and finally the generator state.
+/// Blocks of the map are run-length encoded column by column, as maps mostly consist of long runs of the same block.
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+	pub preset: Preset,
+	pub level:  Level,
+	pub map:    Map,
+	pub rng:    ChaCha8Rng,
+}
+
+impl Snapshot {
+	/// The magic number that all snapshots start with.
+	pub const MAGIC: [u8; 0x8] = *b"BEDROCK\x1A";
+
+	/// The version of the snapshot format.
+	///
+	/// This is bumped whenever the layout changes:
+	///
+	/// 1. Initial layout,
+	/// 2. Level bitmaps,
+	/// 3. Chunk blends and terrain noise,
+	/// 4. Turn times and round counts in presets, and
+	/// 5. Generator state.
+	pub const FORMAT: u32 = 0x5;
+
+	/// The conventional file extension of snapshots.
+	pub const EXTENSION: &'static str = "bedrock";
+}
@@ -0,0 +1,70 @@
+// Copyright 2025 Gabriel Bjørnager Jensen.
+
+use crate::level::{Level, Material};
+use crate::map::{Map, MapSize};
+use crate::preset::Preset;
+use crate::snapshot::Snapshot;
+
+use polywave::www::Html;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+#[test]
+fn snapshot_survives_encoding() {
+	let map_size = MapSize::new(0x20, 0x10).unwrap();
+
+	let preset = Preset {
+		map_size,
+		seed: 0x0123_4567_89AB_CDEF,
+
+		..Default::default()
+	};
+
+	let level = Level {
+		background: Html::from_u32(0x1234_5678),
+
+		..Default::default()
+	};
+
+	let mut map = Map::new(map_size);
+
+	for (index, block) in map.as_mut_slice().iter_mut().enumerate() {
+		let material = match index % 0x5 {
+			0x0 => Material::Rock,
+			0x1 => Material::Water,
+			0x2 => Material::Sand,
+
+			_ => Material::Air,
+		};
+
+		block.set_material(material);
+	}
+
+	let mut rng = ChaCha8Rng::seed_from_u64(preset.seed);
+
+	rng.set_stream(0x7);
+
+	// Advance the generator, so that its position is
+	// also tested.
+
+	for _ in 0x0..0x25 {
+		let _ = rng.next_u32();
+	}
+
+	let snapshot = Snapshot { preset, level, map, rng };
+
+	let decoded = Snapshot::decode(&snapshot.encode()).expect("snapshot should be decodable");
+
+	assert_eq!(decoded.map.size().get(), snapshot.map.size().get());
+	assert_eq!(decoded.map.as_slice(),   snapshot.map.as_slice());
+
+	assert_eq!(decoded.level, snapshot.level);
+
+	assert_eq!(decoded.level.background.to_u32(), 0x1234_5678);
+
+	assert_eq!(decoded.rng.get_seed(),     snapshot.rng.get_seed());
+	assert_eq!(decoded.rng.get_stream(),   snapshot.rng.get_stream());
+	assert_eq!(decoded.rng.get_word_pos(), snapshot.rng.get_word_pos());
+
+	assert_eq!(decoded.preset.seed, snapshot.preset.seed);
+}
@@ -15,7 +15,7 @@ impl Version {
 		major: 0x0,
 		minor: 0x5,
 		patch: 0x0,
-		pre:   Some(0x7),
+		pre:   Some(0x8),
 	};
 }
 